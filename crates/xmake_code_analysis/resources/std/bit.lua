@@ -0,0 +1,45 @@
+---@meta
+--
+-- Bit operations of the xmake script scope.
+-- Reference: xmake/core/base/bit.lua
+
+---@class bitlib
+bit = {}
+
+---@param a integer
+---@param b integer
+---@return integer
+function bit.band(a, b) end
+
+---@param a integer
+---@param b integer
+---@return integer
+function bit.bor(a, b) end
+
+---@param a integer
+---@param b integer
+---@return integer
+function bit.bxor(a, b) end
+
+---@param a integer
+---@return integer
+function bit.bnot(a) end
+
+---@param a integer
+---@param n integer
+---@return integer
+function bit.lshift(a, n) end
+
+---@param a integer
+---@param n integer
+---@return integer
+function bit.rshift(a, n) end
+
+---@param a integer
+---@return integer
+function bit.tobit(a) end
+
+---@param a integer
+---@param n? integer Number of hex digits
+---@return string
+function bit.tohex(a, n) end
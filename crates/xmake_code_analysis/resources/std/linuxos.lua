@@ -0,0 +1,19 @@
+---@meta
+--
+-- Linux helpers of the xmake script scope.
+-- Reference: xmake/core/base/linuxos.lua
+
+---@class linuxos
+linuxos = {}
+
+--- Name of the distribution, e.g. `ubuntu`, `archlinux`
+---@return string
+function linuxos.name() end
+
+--- Version of the distribution
+---@return table
+function linuxos.version() end
+
+--- Version of the kernel
+---@return table
+function linuxos.kernelver() end
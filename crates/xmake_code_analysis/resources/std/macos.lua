@@ -0,0 +1,11 @@
+---@meta
+--
+-- macOS helpers of the xmake script scope.
+-- Reference: xmake/core/base/macos.lua
+
+---@class macos
+macos = {}
+
+--- Version of the running macOS, comparable with `macos.version():ge("10.15")`
+---@return table
+function macos.version() end
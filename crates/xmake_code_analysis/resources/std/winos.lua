@@ -0,0 +1,26 @@
+---@meta
+--
+-- Windows helpers of the xmake script scope.
+-- Reference: xmake/core/base/winos.lua
+
+---@class winos
+winos = {}
+
+--- Version of the running Windows, comparable with `winos.version():ge("win10")`
+---@return table
+function winos.version() end
+
+--- Query a value in the registry, e.g. `HKEY_LOCAL_MACHINE\\SOFTWARE\\Key;Value`
+---@param keypath string
+---@return string?
+function winos.registry_query(keypath) end
+
+--- Sub-keys of a registry key
+---@param keypath string
+---@return string[]
+function winos.registry_keys(keypath) end
+
+--- Value names of a registry key
+---@param keypath string
+---@return string[]
+function winos.registry_values(keypath) end
@@ -140,11 +140,119 @@
 ---@class RepositoriesOption
 ---@field rootdir string? Set root directory of repo
 
----TODO: add more
+---Attributes of `target(name, attr)`, each field calls the `set_*` or `add_*` api of the same name
+---of the target scope, and `on_*`, `before_*` or `after_*` fields set the script of that api.
+---Keep the fields in sync with those apis, a test compares them.
 ---@class TargetAttr
 ---@field kind TargetKind? Set target kind
----@field files string[]? Set target files
----@field includedirs string? Set target include directories
+---@field strip StripMode? Strip target symbols
+---@field enabled boolean? Enable or disable target
+---@field default boolean? Mark as default target
+---@field filename string? Set the full name of target file
+---@field prefixname string? Set the leading name of the target file
+---@field suffixname string? Set the postname of the target file
+---@field extension string? Set the extension of the target file
+---@field warnings WarningLevel|WarningLevel[]? Set compilation warning level
+---@field optimize OptimizationLevel? Set competition optimization level
+---@field languages LanguageStandard|LanguageStandard[]? Set source code language standards
+---@field fpmodels FloatPointModel|FloatPointModel[]? Set float-point compilation mode
+---@field targetdir string? Set output directories for target files
+---@field objectdir string? Set output directories for object files
+---@field dependir string? Set output directories for dependent files
+---@field imports string|string[]? Add imports modules for the custom script
+---@field rules string|string[]? Add custom compilation rule to target
+---@field on_load TargetHook? Run custom load target configuration script
+---@field on_config TargetHook? custom configuration script
+---@field on_link TargetHook? Run custom link target script
+---@field on_build TargetHook? Run custom build target script
+---@field on_build_file TargetBuildFileHook? Run custom build single file script
+---@field on_build_files TargetBuildFilesHook? Run custom build files script
+---@field on_clean TargetHook? Run custom clean files script
+---@field on_package TargetHook? Run custom package target script
+---@field on_install TargetHook? Run custom install target file script
+---@field on_uninstall TargetHook? Run custom uninstall target file script
+---@field on_run TargetHook? Run custom run target script
+---@field before_link TargetHook? Run custom script before linking target
+---@field before_build TargetHook? Run custom script before building target
+---@field before_build_file TargetBuildFileHook? Run custom script before building single file
+---@field before_build_files TargetBuildFilesHook? Run custom script before building files
+---@field before_clean TargetHook? Run custom script before cleaning target
+---@field before_package TargetHook? Run custom script before packaging target
+---@field before_install TargetHook? Run custom script before installing target
+---@field before_uninstall TargetHook? Run custom script before uninstalling target
+---@field before_run TargetHook? Run custom script before running target
+---@field after_link TargetHook? Run custom script after linking target
+---@field after_build TargetHook? Run custom script after building target
+---@field after_build_file TargetBuildFileHook? Run custom script after building single file
+---@field after_build_files TargetBuildFilesHook? Run custom script after building files
+---@field after_clean TargetHook? Run custom script after cleaning target
+---@field after_package TargetHook? Run custom script after packaging target
+---@field after_install TargetHook? Run custom script after installing target
+---@field after_uninstall TargetHook? Run custom script after uninstalling target
+---@field after_run TargetHook? Run custom script after running target
+---@field pcheader string? Set pre-compiled c header file
+---@field pcxxheader string? Set pre-compiled c++ header file
+---@field deps string|string[]? Add target dependencies
+---@field links string|string[]? Add link libraries
+---@field syslinks string|string[]? Add system link libraries
+---@field files string|string[]? Add source files
+---@field linkdirs string|string[]? Add link search directories
+---@field rpathdirs string|string[]? Add load search directories for dynamic libraries
+---@field includedirs string|string[]? Add include search directories
+---@field sysincludedirs string|string[]? Add system header file search directory
+---@field defines string|string[]? Add macro definition
+---@field undefines string|string[]? Add macro undefinition
+---@field cflags string|string[]? Add c compilation flags
+---@field cxflags string|string[]? Add c/c++ compilation flags
+---@field cxxflags string|string[]? Add c++ compilation flags
+---@field mflags string|string[]? Add objc compilation flags
+---@field mxflags string|string[]? Add objc/objc++ compilation flags
+---@field mxxflags string|string[]? Add objc++ compilation flags
+---@field scflags string|string[]? Add swift compilation flags
+---@field asflags string|string[]? Add asm compilation flags
+---@field gcflags string|string[]? Add go compilation flags
+---@field dcflags string|string[]? Add dlang compilation flags
+---@field rcflags string|string[]? Add rust compilation flags
+---@field fcflags string|string[]? Add fortran compilation flags
+---@field zcflags string|string[]? Add zig compilation flags
+---@field cuflags string|string[]? Add cuda compilation flags
+---@field culdflags string|string[]? Add cuda device link flags
+---@field cugencodes string|string[]? Add gencode settings for cuda devices
+---@field ldflags string|string[]? Add static library link flags
+---@field arflags string|string[]? Add archive library flags
+---@field shflags string|string[]? Add dynamic library link flags
+---@field options string|string[]? Add option dependencies
+---@field packages string|string[]? Add package dependencies
+---@field vectorexts string|string[]? Add vector extensions
+---@field frameworks string|string[]? Add frameworks
+---@field frameworkdirs string|string[]? Add framework search directories
+---@field toolset any? Set toolset
+---@field toolchains Toolchain|Toolchain[]? Set up the toolchain
+---@field plat Platform? Set the compilation platform for the specified target
+---@field arch Architecture? Set the compilation architecture of the specified target
+---@field values any? Set custom configuration values
+---@field rundir string? Set the running directory
+---@field runargs string|string[]? Set the list of run parameters
+---@field runenvs any? Add runtime environment variables
+---@field runenv any? Set the runtime environment variable
+---@field installdir string? Set the installation directory
+---@field installfiles string|string[]? Add installation files
+---@field headerfiles string|string[]? Add header files
+---@field configdir string? Set the output directory of configuration files
+---@field configvar any? Set template configuration variables
+---@field configfiles string|string[]? Add template configuration files
+---@field policy any? Set build policy
+---@field runtimes Runtime|Runtime[]? Set the runtime library of the compilation target
+---@field group string? Set target group
+---@field filegroups any? Add Source file groups
+---@field exceptions ExceptionType|ExceptionType[]? Enabling or disabling exceptions
+---@field symbols SymbolInfo|SymbolInfo[]? Set symbol info
+---@field basename string? Set the base name of the target file
+---@field encodings string|string[]? Set source file encodings
+---@field license string? Set target license
+---@field forceincludes string|string[]? Add header files included before every source file
+---@field extrafiles string|string[]? Add extra files shown in generated project files
+---@field version string? Set target version
 
 ---@alias TargetHook fun(target: Target): nil
 
@@ -172,7 +280,10 @@
 ---@alias TargetBuildcmdFileHook fun(target: Target, cmds: BatchCommand, file: string, opt: TargetOpt): nil
 ---@alias TargetBuildcmdFilesHook fun(target: Target, cmds: BatchCommand, files: SourceBatch, opt: TargetOpt): nil
 
----@alias TaskMenuOption string[]
+---A task menu option, `{short, long, kind, default, description, values...}`, e.g.
+---`{'k', "key", "kv", nil, "The key"}`. kind is `k` for a flag, `kv` for a key with a value,
+---`v` or `vs` for one or more positional values. Read it with `option.get("key")`.
+---@alias TaskMenuOption any[]
 
 ---@class TaskMenu
 ---@field usage string? Menu usage
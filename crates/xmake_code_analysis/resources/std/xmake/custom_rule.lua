@@ -64,6 +64,7 @@ function on_load(func) end
 ---custom configuration script
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#on_config)
+---@since 2.5.1
 ---@scope rule
 ---@param func TargetHook Function to run for custom config
 ---@return nil
@@ -73,6 +74,7 @@ function on_config(func) end
 ---Custom link script
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#on_link)
+---@since 2.2.7
 ---@scope rule
 ---@param func TargetHook Function to run when link
 ---@return nil
@@ -136,6 +138,7 @@ function on_build_file(func) end
 ---Custom batch compile script, process one source file at a time
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#on_buildcmd_file)
+---@since 2.5.2
 ---@scope rule
 ---@param func TargetBuildcmdFileHook Function to run for custom build
 ---@return nil
@@ -154,6 +157,7 @@ function on_build_files(func) end
 ---Customize batch compiling script, process multiple source files at once
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#on_buildcmd_files)
+---@since 2.5.2
 ---@scope rule
 ---@param func TargetBuildcmdFilesHook Function to run for custom build
 ---@return nil
@@ -172,6 +176,7 @@ function before_config(func) end
 ---Custom pre-link script
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#before_link)
+---@since 2.2.7
 ---@scope rule
 ---@param func TargetHook Function to run before linking target
 ---@return nil
@@ -235,6 +240,7 @@ function before_build_file(func) end
 ---Customize the pre-compilation batch script, process one source file at a time
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#before_buildcmd_file)
+---@since 2.5.2
 ---@scope rule
 ---@param func TargetBuildcmdFileHook Function to run before building
 ---@return nil
@@ -253,6 +259,7 @@ function before_build_files(func) end
 ---Customize the pre-compilation batch script to process multiple source files at once
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#before_buildcmd_files)
+---@since 2.5.2
 ---@scope rule
 ---@param func TargetBuildcmdFilesHook Function to run before building
 ---@return nil
@@ -262,6 +269,7 @@ function before_buildcmd_files(func) end
 ---Custom post-linking script
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#after_link)
+---@since 2.2.7
 ---@scope rule
 ---@param func TargetHook Function to run after linking target
 ---@return nil
@@ -325,6 +333,7 @@ function after_build_file(func) end
 ---Customize the compiled batch script, process one source file at a time
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#after_buildcmd_file)
+---@since 2.5.2
 ---@scope rule
 ---@param func TargetBuildcmdFileHook Function to run after building
 ---@return nil
@@ -343,6 +352,7 @@ function after_build_files(func) end
 ---Customize the compiled batch script to process multiple source files at once
 ---
 ---[Open in browser](https://xmake.io/api/description/custom-rule#after_buildcmd_files)
+---@since 2.5.2
 ---@scope rule
 ---@param func TargetBuildcmdFilesHook Function to run after building
 ---@return nil
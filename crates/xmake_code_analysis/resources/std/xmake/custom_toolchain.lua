@@ -0,0 +1,103 @@
+---@meta
+---[custom_toolchain](https://xmake.io/api/description/custom-toolchain)
+
+---
+---Define toolchain
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#toolchain)
+--- Example:
+--- ```lua
+--- toolchain("myclang")
+---     set_kind("standalone")
+---     set_toolset("cc", "clang")
+---     set_toolset("cxx", "clang", "clang++")
+--- toolchain_end()
+--- ```
+---@since 2.3.4
+---@scope toolchain
+---@param name string Toolchain name
+---@param func? fun(): nil Toolchain scoped function
+---@return nil
+function toolchain(name, func) end
+
+---
+---End toolchain definition
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#toolchain_end)
+---@since 2.3.4
+---@return nil
+function toolchain_end() end
+
+---
+---Set toolchain type
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#set_kind)
+---@scope toolchain
+---@param kind "standalone" Toolchain kind
+---@return nil
+function set_kind(kind) end
+
+---
+---Set toolset
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#set_toolset)
+---@scope toolchain
+---@param type ToolType Tool type
+---@param name string Tool name or location
+---@param ... string Fallback tool names
+---@return nil
+function set_toolset(type, name, ...) end
+
+---
+---Set toolchain sdk directory
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#set_sdkdir)
+---@scope toolchain
+---@param dir string Sdk directory
+---@return nil
+function set_sdkdir(dir) end
+
+---
+---Set toolchain bin directory
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#set_bindir)
+---@scope toolchain
+---@param dir string Bin directory
+---@return nil
+function set_bindir(dir) end
+
+---
+---Set toolchain homepage
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#set_homepage)
+---@scope toolchain
+---@param link string Homepage link
+---@return nil
+function set_homepage(link) end
+
+---
+---Set toolchain description
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#set_description)
+---@scope toolchain
+---@param description string Toolchain description
+---@return nil
+function set_description(description) end
+
+---
+---Check whether the toolchain is available
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#on_check)
+---@scope toolchain
+---@param func fun(toolchain: any): boolean? Function returning whether the toolchain is found
+---@return nil
+function on_check(func) end
+
+---
+---Custom load script
+---
+---[Open in browser](https://xmake.io/api/description/custom-toolchain#on_load)
+---@scope toolchain
+---@param func fun(toolchain: any): nil Function to run when the toolchain is loaded
+---@return nil
+function on_load(func) end
@@ -21,12 +21,12 @@ function includes(dir_or_file, ...) end
 function set_project(name) end
 
 ---
----Set project version
+---Set project version, or the version of the target when called in a target
 ---
 ---[Open in browser](https://xmake.io/api/description/global-interfaces#set_version)
 ---
----@param version string Project version
----@param extra? { build: string } Build version in datetime format, like "%Y%m%d%H%M"
+---@param version string Project or target version
+---@param extra? { build: string, soname: boolean|string } Build version in datetime format, like "%Y%m%d%H%M", and the soname of a target
 ---@return nil
 function set_version(version, extra) end
 
@@ -64,6 +64,7 @@ function add_plugindirs(dir, ...) end
 ---
 ---[Open in browser](https://xmake.io/api/description/global-interfaces#add_toolchaindirs)
 ---
+---@since 2.3.4
 ---@param dir string Toolchain directory
 ---@param ... string Toolchain directories
 ---@return nil
@@ -93,6 +94,7 @@ function set_config(key, value) end
 ---
 ---[Open in browser](https://xmake.io/api/description/global-interfaces#add_requires)
 ---
+---@since 2.2.2
 ---@param name string Required dependency package name
 ---@param ... string Required dependency package names
 ---@return nil
@@ -103,6 +105,7 @@ function add_requires(name, ...) end
 ---
 ---[Open in browser](https://xmake.io/api/description/global-interfaces#add_requires)
 ---
+---@since 2.2.2
 ---@param name string Required dependency package name
 ---@param option? RequiresOption Options
 ---@return nil
@@ -113,6 +116,7 @@ function add_requires(name, option) end
 ---
 ---[Open in browser](https://xmake.io/api/description/global-interfaces#add_requireconfs)
 ---
+---@since 2.5.1
 ---@param name string Package name
 ---@param option RequireconfsOption Option
 ---@return nil
@@ -123,6 +127,7 @@ function add_requireconfs(name, option) end
 ---
 ---[Open in browser](https://xmake.io/api/description/global-interfaces#add_repositories)
 ---
+---@since 2.2.2
 ---@param  repo string Repo name and location
 ---@param ... string Repo names and repo locations
 ---@return nil
@@ -133,6 +138,7 @@ function add_repositories(repo, ...) end
 ---
 ---[Open in browser](https://xmake.io/api/description/global-interfaces#add_repositories)
 ---
+---@since 2.2.2
 ---@param repo string Repo name and repo location, separated by " "
 ---@param option? RepositoriesOption Option
 ---@return nil
@@ -223,3 +229,54 @@ function raise(message) end
 function printf(...) end
 
 function cprint(...) end
+
+--- Print formatted text with colors, without a newline
+---
+---[Open in browser](https://xmake.io/api/scripts/builtin-modules/cprintf.html#cprintf)
+---
+---@param format string
+---@param ... any
+function cprintf(format, ...) end
+
+--- Format a string like `string.format`
+---
+--- ```lua
+--- local s = format("%s-%d", "app", 1)
+--- ```
+---
+---[Open in browser](https://xmake.io/api/scripts/builtin-modules/format.html#format)
+---
+---@param format string
+---@param ... any
+---@return string
+function format(format, ...) end
+
+--- Format a string and expand builtin variables like `$(buildir)` and `$(env PATH)`
+---
+---[Open in browser](https://xmake.io/api/scripts/builtin-modules/vformat.html#vformat)
+---
+---@param format string
+---@param ... any
+---@return string
+function vformat(format, ...) end
+
+--- Print only in verbose mode (`-v`)
+---@param ... any
+function vprint(...) end
+
+--- Print formatted text only in verbose mode (`-v`)
+---@param format string
+---@param ... any
+function vprintf(format, ...) end
+
+--- Import a module and inherit its exported functions into the current module
+---
+---[Open in browser](https://xmake.io/api/scripts/builtin-modules/inherit.html#inherit)
+---
+---@param module_name string Module name, e.g. `core.tools.gcc`
+function inherit(module_name) end
+
+--- Value of a builtin variable, e.g. `val("host")`, `val("projectdir")`
+---@param name string
+---@return string
+function val(name) end
@@ -10,6 +10,7 @@
 --- package("zlib")
 --- ```
 ---
+---@since 2.2.2
 ---@param name string Package name
 ---@return nil
 function package(name) end
@@ -243,6 +244,7 @@ function add_configs(name, def) end
 --- ```lua
 --- add_extsources("cmake::zlib", "pkgconfig::zlib")
 --- ```
+---@since 2.5.2
 ---@scope package
 ---@param source string External package source
 ---@param ... string External package sources
@@ -75,6 +75,7 @@ function set_filename(name) end
 ---Set the leading name of the target file
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_prefixname)
+---@since 2.5.5
 ---@scope target
 ---@param name string Prefix name
 ---@return nil
@@ -84,6 +85,7 @@ function set_prefixname(name) end
 ---Set the postname of the target file
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_suffixname)
+---@since 2.5.5
 ---@scope target
 ---@param name string Suffix name
 ---@return nil
@@ -93,6 +95,7 @@ function set_suffixname(name) end
 ---Set the extension of the target file
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_extension)
+---@since 2.5.5
 ---@scope target
 ---@param ext string Extension
 ---@return nil
@@ -229,6 +232,7 @@ function on_load(func) end
 ---custom configuration script
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#on_config)
+---@since 2.5.1
 ---@scope target
 ---@param func TargetHook Function to run for custom config
 ---@return nil
@@ -238,6 +242,7 @@ function on_config(func) end
 ---Run custom link target script
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#on_link)
+---@since 2.2.7
 ---@scope target
 ---@param func TargetHook Function to run when link
 ---@return nil
@@ -342,6 +347,7 @@ function on_run(func) end
 ---Run custom script before linking target
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#before_link)
+---@since 2.2.7
 ---@scope target
 ---@param func TargetHook Function to run before linking target
 ---@return nil
@@ -423,6 +429,7 @@ function before_run(func) end
 ---Run custom script after linking target
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#after_link)
+---@since 2.2.7
 ---@scope target
 ---@param func TargetHook Function to run after linking target
 ---@return nil
@@ -1038,6 +1045,7 @@ function add_frameworkdirs(dir, ...) end
 --- target("app")
 ---     set_toolset("cc", "clang")
 --- ```
+---@since 2.3.4
 ---@scope target
 ---@param type ToolType Tool type
 ---@param name string Tool name or location
@@ -1054,6 +1062,7 @@ function set_toolset(type, name) end
 --- target("app")
 ---     set_toolchains("clang")
 --- ```
+---@since 2.3.4
 ---@scope target
 ---@param name Toolchain Toolchain name
 ---@param ... Toolchain Toolchain names
@@ -1065,12 +1074,25 @@ function set_toolchains(name, ...) end
 ---Run `xmake show -l toolchains` for full list
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_toolchains)
+---@since 2.3.4
 ---@scope target
 ---@param name Toolchain Toolchain name
 ---@param option ToolchainOption Toolchain option
 ---@return nil
 function set_toolchains(name, option) end
 
+---
+---Add toolchains
+---
+---Run `xmake show -l toolchains` for full list
+---
+---[Open in browser](https://xmake.io/api/description/project-target#set_toolchains)
+---@scope target
+---@param name Toolchain Toolchain name
+---@param ... Toolchain Toolchain names
+---@return nil
+function add_toolchains(name, ...) end
+
 ---
 ---Set the compilation platform for the specified target
 ---
@@ -1184,6 +1206,7 @@ function set_runenv(key, value, ...) end
 --- target("app")
 ---     set_installdir("dist")
 --- ```
+---@since 2.2.5
 ---@scope target
 ---@param dir string Installation directory
 ---@return nil
@@ -1197,6 +1220,7 @@ function set_installdir(dir) end
 --- target("app")
 ---     add_installfiles("res/**")
 --- ```
+---@since 2.2.5
 ---@scope target
 ---@param file string File name
 ---@param ... string File names
@@ -1207,6 +1231,7 @@ function add_installfiles(file, ...) end
 ---Add installation files
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#add_installfiles)
+---@since 2.2.5
 ---@scope target
 ---@param file string File name
 ---@param option InstallFilesOption Install files option
@@ -1217,6 +1242,7 @@ function add_installfiles(file, option) end
 ---Add header files
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#add_headerfiles)
+---@since 2.2.5
 ---@scope target
 ---@param file string File name
 ---@param ... string File names
@@ -1227,6 +1253,7 @@ function add_headerfiles(file, ...) end
 ---Add header files
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#add_headerfiles)
+---@since 2.2.5
 ---@scope target
 ---@param file string File name
 ---@param option InstallFilesOption Install files option
@@ -1237,6 +1264,7 @@ function add_headerfiles(file, option) end
 ---Set the output directory of configuration files
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_configdir)
+---@since 2.2.5
 ---@scope target
 ---@param dir string Output directory
 ---@return nil
@@ -1246,6 +1274,7 @@ function set_configdir(dir) end
 ---Set template configuration variables
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_configvar)
+---@since 2.2.5
 ---@scope target
 ---@param key string Template config key
 ---@param value string Template config value
@@ -1257,6 +1286,7 @@ function set_configvar(key, value, option) end
 ---Add template configuration files
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#add_configfiles)
+---@since 2.2.5
 ---@scope target
 ---@param file string Template config file name
 ---@param ... string Template config file names
@@ -1267,6 +1297,7 @@ function add_configfiles(file, ...) end
 ---Add template configuration files
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#add_configfiles)
+---@since 2.2.5
 ---@scope target
 ---@param file string Template config file name
 ---@param option ConfigfilesOption Configfiles option
@@ -1279,6 +1310,7 @@ function add_configfiles(file, option) end
 ---Run `xmake l core.project.policy.policies` for full list
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_policy)
+---@since 2.3.4
 ---@scope target
 ---@param name BuildPolicy
 ---@param value any Policy value
@@ -1290,6 +1322,7 @@ function set_policy(name, value, ...) end
 ---Set the runtime library of the compilation target
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_runtimes)
+---@since 2.5.1
 ---@scope target
 ---@param runtime Runtime Runtime name
 ---@param ... Runtime Runtime names
@@ -1313,6 +1346,7 @@ function set_group(name) end
 ---Add Source file groups
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#add_filegroups)
+---@since 2.6.8
 ---@scope target
 ---@param group string Path for the group
 ---@param option FilegroupsOption Filegroups option
@@ -1323,8 +1357,67 @@ function add_filegroups(group, option) end
 ---Enabling or disabling exceptions
 ---
 ---[Open in browser](https://xmake.io/api/description/project-target#set_exceptions)
+---@since 2.8.2
 ---@scope target
 ---@param type ExceptionType Exception type
 ---@param ... ExceptionType Exception types
 ---@return nil
 function set_exceptions(type, ...) end
+
+---
+---Set symbol info
+---
+---[Open in browser](https://xmake.io/api/description/project-target#set_symbols)
+---@scope target
+---@param mode SymbolInfo Symbol info mode
+---@param ... SymbolInfo Symbol info modes
+---@return nil
+function set_symbols(mode, ...) end
+
+---
+---Set the base name of the target file
+---
+---[Open in browser](https://xmake.io/api/description/project-target#set_basename)
+---@scope target
+---@param name string Base name
+---@return nil
+function set_basename(name) end
+
+---
+---Set source file encodings
+---
+---[Open in browser](https://xmake.io/api/description/project-target#set_encodings)
+---@scope target
+---@param encoding string Encoding, like "utf-8"
+---@param ... string Encodings
+---@return nil
+function set_encodings(encoding, ...) end
+
+---
+---Set target license
+---
+---[Open in browser](https://xmake.io/api/description/project-target#set_license)
+---@scope target
+---@param license string License name, like "MIT"
+---@return nil
+function set_license(license) end
+
+---
+---Add header files included before every source file
+---
+---[Open in browser](https://xmake.io/api/description/project-target#add_forceincludes)
+---@scope target
+---@param file string Header file
+---@param ... string Header files
+---@return nil
+function add_forceincludes(file, ...) end
+
+---
+---Add extra files shown in generated project files
+---
+---[Open in browser](https://xmake.io/api/description/project-target#add_extrafiles)
+---@scope target
+---@param file string Extra file
+---@param ... string Extra files
+---@return nil
+function add_extrafiles(file, ...) end
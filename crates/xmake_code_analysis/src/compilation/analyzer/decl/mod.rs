@@ -253,6 +253,9 @@ impl<'a> DeclAnalyzer<'a> {
         self.db
             .get_xmake_index_mut()
             .add_includes(file_id, include_file_id);
+        self.db
+            .get_file_dependencies_index_mut()
+            .add_required_file(file_id, include_file_id);
     }
 }
 
@@ -1,11 +1,13 @@
 use emmylua_parser::{
-    LuaAstNode, LuaCallExpr, LuaCallExprStat, LuaExpr, LuaIndexKey, LuaLiteralToken, LuaStat,
+    LuaAstNode, LuaAstToken, LuaCallExpr, LuaCallExprStat, LuaClosureExpr, LuaExpr, LuaIndexKey,
+    LuaLiteralToken, LuaStat, LuaSyntaxNode, LuaTableExpr,
 };
-use rowan::{TextRange, TextSize};
+use rowan::{Direction, TextRange, TextSize};
 
 use crate::{
-    LuaDecl, LuaDeclExtra, XmakeFunction, XmakeTarget, XmakeTargetKind,
-    compilation::analyzer::decl::DeclAnalyzer, get_xmake_function,
+    LuaDecl, LuaDeclExtra, XmakeFunction, XmakeMenuOption, XmakeTarget, XmakeTargetKind,
+    XmakeTargetProperties, XmakeTargetValue, compilation::analyzer::decl::DeclAnalyzer,
+    get_xmake_function, parse_xmake_version,
 };
 
 pub fn analyze_xmake_function_call(
@@ -35,6 +37,12 @@ pub fn analyze_xmake_function_call(
         XmakeFunction::Task => {
             analyze_target(analyzer, call_expr, XmakeTargetKind::Task);
         }
+        XmakeFunction::Toolchain => {
+            analyze_target(analyzer, call_expr, XmakeTargetKind::Toolchain);
+        }
+        XmakeFunction::SetXmakever => {
+            analyze_set_xmakever(analyzer, call_expr);
+        }
         _ => {}
     }
 
@@ -125,12 +133,41 @@ fn analyze_includes(analyzer: &mut DeclAnalyzer, call_expr: &LuaCallExpr) -> Opt
     };
 
     let include_path = string_token.get_value();
-    let founded_module_info = analyzer.db.get_module_index().find_include(
-        &analyzer.db,
-        &include_path,
-        analyzer.get_file_id(),
-    )?;
-    analyzer.add_include_path(founded_module_info.file_id);
+    let file_id = analyzer.get_file_id();
+    let module_index = analyzer.db.get_module_index();
+    match module_index.find_include(analyzer.db, &include_path, file_id) {
+        Some(founded_module_info) => {
+            let include_file_id = founded_module_info.file_id;
+            analyzer.add_include_path(include_file_id);
+        }
+        // retried once the file is indexed
+        None => {
+            let path = module_index.get_include_path(analyzer.db, &include_path, file_id)?;
+            analyzer
+                .db
+                .get_file_dependencies_index_mut()
+                .add_unresolved_include(file_id, path);
+        }
+    }
+
+    Some(())
+}
+
+fn analyze_set_xmakever(analyzer: &mut DeclAnalyzer, call_expr: &LuaCallExpr) -> Option<()> {
+    let first_arg = call_expr.get_args_list()?.get_args().next()?;
+    let LuaExpr::LiteralExpr(literal) = first_arg else {
+        return None;
+    };
+    let LuaLiteralToken::String(string_token) = literal.get_literal()? else {
+        return None;
+    };
+
+    let version = parse_xmake_version(&string_token.get_value())?;
+    let file_id = analyzer.get_file_id();
+    analyzer
+        .db
+        .get_xmake_index_mut()
+        .add_required_version(file_id, version);
 
     Some(())
 }
@@ -157,15 +194,31 @@ fn analyze_target(
 
     let target_name = string_token.get_value();
     let file_id = analyzer.get_file_id();
-    let range = if args.len() > 1 {
-        args[1].get_range()
+    let (range, end_call_range, properties) = if args.len() > 1 {
+        let range = args[1].get_range();
+        let mut properties =
+            collect_target_properties(std::iter::once(args[1].syntax().clone()), range);
+        if let LuaExpr::TableExpr(table_expr) = &args[1] {
+            collect_table_properties(table_expr, &mut properties);
+        }
+        (range, None, properties)
     } else {
         let stat = call_expr.ancestors::<LuaStat>().next()?;
-        let end_position = get_end_position(&stat, kind)?;
+        let (end_position, end_call_range) = get_end_position(&stat, kind)?;
         if end_position <= stat.get_range().end() {
             return None;
         }
-        TextRange::new(stat.get_range().end(), end_position)
+        let range = TextRange::new(stat.get_range().end(), end_position);
+        let body = stat
+            .syntax()
+            .siblings(Direction::Next)
+            .skip(1)
+            .take_while(|node| node.text_range().start() < end_position);
+        (
+            range,
+            end_call_range,
+            collect_target_properties(body, range),
+        )
     };
 
     analyzer.db.get_xmake_index_mut().add_target_or_package(
@@ -174,13 +227,131 @@ fn analyze_target(
             name: target_name,
             kind,
             range,
+            name_range: string_token.get_range(),
+            end_call_range,
+            properties,
         },
     );
 
     Some(())
 }
 
-fn get_end_position(stat: &LuaStat, target_kind: XmakeTargetKind) -> Option<TextSize> {
+/// Collect the description apis called in a scope body, scripts like `on_load` are skipped
+fn collect_target_properties(
+    body: impl Iterator<Item = LuaSyntaxNode>,
+    range: TextRange,
+) -> XmakeTargetProperties {
+    let mut properties = XmakeTargetProperties::default();
+    for node in body {
+        for call_expr in node.descendants().filter_map(LuaCallExpr::cast) {
+            let in_script = call_expr
+                .ancestors::<LuaClosureExpr>()
+                .any(|closure| closure.get_range() != range);
+            if in_script {
+                continue;
+            }
+            let Some(LuaExpr::NameExpr(name_expr)) = call_expr.get_prefix_expr() else {
+                continue;
+            };
+            let Some(name) = name_expr.get_name_text() else {
+                continue;
+            };
+            let Some(arg_list) = call_expr.get_args_list() else {
+                continue;
+            };
+
+            if name == "set_menu"
+                && let Some(LuaExpr::TableExpr(menu)) = arg_list.get_args().next()
+            {
+                properties.has_menu = true;
+                properties.menu_options = collect_menu_options(&menu);
+                continue;
+            }
+
+            let values = arg_list.get_args().filter_map(get_string_value).collect();
+            properties.add(&name, values);
+        }
+    }
+
+    properties
+}
+
+/// Collect the fields of `target("app", {kind = "binary", deps = {"core"}})`
+fn collect_table_properties(table_expr: &LuaTableExpr, properties: &mut XmakeTargetProperties) {
+    for field in table_expr.get_fields() {
+        let Some(LuaIndexKey::Name(name)) = field.get_field_key() else {
+            continue;
+        };
+        let values = match field.get_value_expr() {
+            Some(LuaExpr::TableExpr(values)) => values
+                .get_fields()
+                .filter_map(|value| value.get_value_expr())
+                .filter_map(get_string_value)
+                .collect(),
+            Some(value) => get_string_value(value).into_iter().collect(),
+            None => continue,
+        };
+        properties.add_field(name.get_name_text(), values);
+    }
+}
+
+/// The `options` of `set_menu {options = {{"k", "key", "kv", nil, "The key"}, {}}}`, entries
+/// without a long name like the `{}` separators are skipped
+fn collect_menu_options(menu: &LuaTableExpr) -> Vec<XmakeMenuOption> {
+    let options = menu
+        .get_fields()
+        .find_map(|field| match field.get_field_key()? {
+            LuaIndexKey::Name(name) if name.get_name_text() == "options" => field.get_value_expr(),
+            _ => None,
+        });
+    let Some(LuaExpr::TableExpr(options)) = options else {
+        return Vec::new();
+    };
+
+    options
+        .get_fields()
+        .filter_map(|option| match option.get_value_expr()? {
+            LuaExpr::TableExpr(option) => collect_menu_option(&option),
+            _ => None,
+        })
+        .collect()
+}
+
+fn collect_menu_option(option: &LuaTableExpr) -> Option<XmakeMenuOption> {
+    // positional, `nil` values keep their slot
+    let values = option
+        .get_fields()
+        .filter(|field| field.is_value_field())
+        .map(|field| field.get_value_expr().and_then(get_string_value))
+        .collect::<Vec<_>>();
+    let get = |index: usize| values.get(index).cloned().flatten();
+
+    Some(XmakeMenuOption {
+        short: get(0).map(|short| short.value),
+        name: get(1)?,
+        kind: get(2)?.value,
+        description: get(4).map(|description| description.value),
+    })
+}
+
+fn get_string_value(expr: LuaExpr) -> Option<XmakeTargetValue> {
+    let LuaExpr::LiteralExpr(literal) = expr else {
+        return None;
+    };
+    let LuaLiteralToken::String(string_token) = literal.get_literal()? else {
+        return None;
+    };
+    Some(XmakeTargetValue {
+        value: string_token.get_value(),
+        range: string_token.get_range(),
+    })
+}
+
+/// The end of a scope without a table or function body, and the `*_end()` call closing it if any
+fn get_end_position(
+    stat: &LuaStat,
+    target_kind: XmakeTargetKind,
+) -> Option<(TextSize, Option<TextRange>)> {
     let mut current_syntax_node = stat.syntax().clone();
     while let Some(next_sibling) = current_syntax_node.next_sibling() {
         if let Some(call_expr_stat) = LuaCallExprStat::cast(next_sibling.clone()) {
@@ -191,8 +362,9 @@ fn get_end_position(stat: &LuaStat, target_kind: XmakeTargetKind) -> Option<Text
                     | (XmakeFunction::EndPackage, XmakeTargetKind::Package)
                     | (XmakeFunction::EndOption, XmakeTargetKind::Option)
                     | (XmakeFunction::EndRule, XmakeTargetKind::Rule)
-                    | (XmakeFunction::EndTask, XmakeTargetKind::Task) => {
-                        return Some(call_expr.get_range().end());
+                    | (XmakeFunction::EndTask, XmakeTargetKind::Task)
+                    | (XmakeFunction::EndToolchain, XmakeTargetKind::Toolchain) => {
+                        return Some((call_expr.get_range().end(), Some(call_expr.get_range())));
                     }
                     // new target/package starts, stop searching
                     (
@@ -200,10 +372,11 @@ fn get_end_position(stat: &LuaStat, target_kind: XmakeTargetKind) -> Option<Text
                         | XmakeFunction::Package
                         | XmakeFunction::Option
                         | XmakeFunction::Rule
-                        | XmakeFunction::Task,
+                        | XmakeFunction::Task
+                        | XmakeFunction::Toolchain,
                         _,
                     ) => {
-                        return Some(call_expr.get_position());
+                        return Some((call_expr.get_position(), None));
                     }
                     _ => {}
                 }
@@ -213,5 +386,5 @@ fn get_end_position(stat: &LuaStat, target_kind: XmakeTargetKind) -> Option<Text
     }
 
     let root = stat.get_root();
-    Some(root.text_range().end())
+    Some((root.text_range().end(), None))
 }
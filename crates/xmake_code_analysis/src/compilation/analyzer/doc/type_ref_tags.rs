@@ -25,6 +25,7 @@ use crate::{
     compilation::analyzer::doc::tags::{
         find_owner_closure_or_report, get_owner_id, report_orphan_tag,
     },
+    parse_xmake_version,
 };
 
 pub fn analyze_type(analyzer: &mut DocAnalyzer, tag: LuaDocTagType) -> Option<()> {
@@ -430,6 +431,7 @@ pub fn analyze_other(analyzer: &mut DocAnalyzer, other: LuaDocTagOther) -> Optio
             "package" => XmakeScope::Package,
             "option" => XmakeScope::Option,
             "rule" => XmakeScope::Rule,
+            "toolchain" => XmakeScope::Toolchain,
             _ => return None,
         };
         analyzer
@@ -439,6 +441,16 @@ pub fn analyze_other(analyzer: &mut DocAnalyzer, other: LuaDocTagOther) -> Optio
         return Some(());
     }
 
+    // keep `@since` as a plain tag as well, so hover still shows it
+    if tag_name == "since"
+        && let Some(since) = parse_xmake_version(&description)
+    {
+        analyzer
+            .db
+            .get_property_index_mut()
+            .add_since(analyzer.file_id, owner.clone(), since);
+    }
+
     analyzer
         .db
         .get_property_index_mut()
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test {
+    use crate::{FileId, VirtualWorkspace};
+
+    fn get_includes(ws: &VirtualWorkspace, file_id: FileId) -> Vec<FileId> {
+        ws.analysis
+            .compilation
+            .get_db()
+            .get_xmake_index()
+            .get_includes(file_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_include_created_later() {
+        let mut ws = VirtualWorkspace::new();
+        let main = ws.def_file("xmake.lua", r#"includes("sub")"#);
+        assert!(get_includes(&ws, main).is_empty());
+
+        let sub = ws.def_file("sub/xmake.lua", r#"target("sub")"#);
+        assert_eq!(get_includes(&ws, main), vec![sub]);
+    }
+
+    #[test]
+    fn test_include_edited_and_removed() {
+        let mut ws = VirtualWorkspace::new();
+        let sub = ws.def_file("sub/xmake.lua", r#"target("sub")"#);
+        let main = ws.def_file("xmake.lua", r#"includes("sub")"#);
+        assert_eq!(get_includes(&ws, main), vec![sub]);
+
+        ws.def_file("sub/xmake.lua", r#"target("sub2")"#);
+        assert_eq!(get_includes(&ws, main), vec![sub]);
+
+        let sub_uri = ws.virtual_url_generator.new_uri("sub/xmake.lua");
+        ws.analysis.remove_file_by_uri(&sub_uri);
+        assert!(get_includes(&ws, main).is_empty());
+
+        // the include is resolved again once the file comes back
+        let sub = ws.def_file("sub/xmake.lua", r#"target("sub")"#);
+        assert_eq!(get_includes(&ws, main), vec![sub]);
+    }
+
+    #[test]
+    fn test_include_interface() {
+        let mut ws = VirtualWorkspace::new();
+        let sub = ws.def_file("sub/xmake.lua", r#"target("sub")"#);
+        let interface = ws.analysis.get_include_interface(sub);
+        assert!(interface.is_some());
+
+        // editing inside the target changes nothing the includers read, they are not re-analyzed
+        ws.def_file(
+            "sub/xmake.lua",
+            r#"
+            target("sub")
+                add_files("*.c")
+            "#,
+        );
+        assert_eq!(ws.analysis.get_include_interface(sub), interface);
+
+        ws.def_file("sub/lib/xmake.lua", "");
+        ws.def_file(
+            "sub/xmake.lua",
+            r#"
+            includes("lib")
+            target("sub")
+            "#,
+        );
+        assert_ne!(ws.analysis.get_include_interface(sub), interface);
+    }
+}
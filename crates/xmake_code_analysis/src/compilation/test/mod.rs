@@ -10,6 +10,7 @@ mod export_test;
 mod flow;
 mod for_range_var_infer_test;
 mod generic_test;
+mod include_dependency_test;
 mod infer_str_tpl_test;
 mod inherit_type;
 mod mathlib_test;
@@ -0,0 +1,17 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EmmyrcCompileCommands {
+    /// Generate `compile_commands.json` with `xmake project -k compile_commands` on startup and
+    /// whenever an xmake file changes, for clangd and other C/C++ tools.
+    #[serde(default)]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub enable: bool,
+    /// Directory `compile_commands.json` is written to, relative to the project directory.
+    /// eg: ".vscode" or "build". Defaults to the project directory.
+    #[serde(default)]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub output_dir: Option<String>,
+}
@@ -0,0 +1,29 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EmmyrcFolding {
+    /// Fold `target`/`package`/`option`/`rule`/`task` blocks, with or without an explicit end.
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub xmake_blocks: bool,
+
+    /// Fold `if is_plat(...)` and similar platform checks as regions named after the condition.
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub platform_regions: bool,
+}
+
+impl Default for EmmyrcFolding {
+    fn default() -> Self {
+        Self {
+            xmake_blocks: default_true(),
+            platform_regions: default_true(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
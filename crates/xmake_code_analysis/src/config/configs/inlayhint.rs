@@ -59,6 +59,19 @@ pub struct EmmyrcInlayHint {
     #[serde(default = "default_false")]
     #[schemars(extend("x-vscode-setting" = true))]
     pub enum_param_hint: bool,
+    /// Show `-- end target "name"` where a target, package, rule, option or task without
+    /// `target_end()` ends.
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub scope_end_hint: bool,
+    /// Show the number of targets pulled in by each `includes(...)`.
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub includes_hint: bool,
+    /// Show the kind set by `set_kind` next to `target("name")`.
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-vscode-setting" = true))]
+    pub target_kind_hint: bool,
 }
 
 impl Default for EmmyrcInlayHint {
@@ -71,6 +84,9 @@ impl Default for EmmyrcInlayHint {
             override_hint: default_true(),
             meta_call_hint: default_true(),
             enum_param_hint: default_false(),
+            scope_end_hint: default_true(),
+            includes_hint: default_true(),
+            target_kind_hint: default_true(),
         }
     }
 }
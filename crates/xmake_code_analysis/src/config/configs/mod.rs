@@ -1,9 +1,11 @@
 mod code_action;
 mod codelen;
+mod compile_commands;
 mod completion;
 mod diagnostics;
 mod doc;
 mod document_color;
+mod folding;
 mod hover;
 mod inlayhint;
 mod inline_values;
@@ -18,10 +20,12 @@ mod workspace;
 
 pub use code_action::EmmyrcCodeAction;
 pub use codelen::EmmyrcCodeLens;
+pub use compile_commands::EmmyrcCompileCommands;
 pub use completion::{EmmyrcCompletion, EmmyrcFilenameConvention};
 pub use diagnostics::{DiagnosticSeveritySetting, EmmyrcDiagnostic};
 pub use doc::{DocSyntax, EmmyrcDoc};
 pub use document_color::EmmyrcDocumentColor;
+pub use folding::EmmyrcFolding;
 pub use hover::EmmyrcHover;
 pub use inlayhint::EmmyrcInlayHint;
 pub use inline_values::EmmyrcInlineValues;
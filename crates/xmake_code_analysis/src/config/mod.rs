@@ -9,11 +9,11 @@ use std::{
 
 pub use config_loader::{load_configs, load_configs_raw};
 pub use configs::{
-    DiagnosticSeveritySetting, DocSyntax, EmmyrcCodeAction, EmmyrcCodeLens, EmmyrcCompletion,
-    EmmyrcDiagnostic, EmmyrcDoc, EmmyrcDocumentColor, EmmyrcExternalTool, EmmyrcFilenameConvention,
-    EmmyrcHover, EmmyrcInlayHint, EmmyrcInlineValues, EmmyrcLuaVersion, EmmyrcReference,
-    EmmyrcReformat, EmmyrcResource, EmmyrcRuntime, EmmyrcSemanticToken, EmmyrcSignature,
-    EmmyrcStrict, EmmyrcWorkspace, EmmyrcWorkspaceModuleMap,
+    DiagnosticSeveritySetting, DocSyntax, EmmyrcCodeAction, EmmyrcCodeLens, EmmyrcCompileCommands,
+    EmmyrcCompletion, EmmyrcDiagnostic, EmmyrcDoc, EmmyrcDocumentColor, EmmyrcExternalTool,
+    EmmyrcFilenameConvention, EmmyrcFolding, EmmyrcHover, EmmyrcInlayHint, EmmyrcInlineValues,
+    EmmyrcLuaVersion, EmmyrcReference, EmmyrcReformat, EmmyrcResource, EmmyrcRuntime,
+    EmmyrcSemanticToken, EmmyrcSignature, EmmyrcStrict, EmmyrcWorkspace, EmmyrcWorkspaceModuleMap,
 };
 use emmylua_parser::{LuaLanguageLevel, LuaNonStdSymbolSet, ParserConfig, SpecialFunction};
 use regex::Regex;
@@ -61,6 +61,10 @@ pub struct Emmyrc {
     pub doc: EmmyrcDoc,
     #[serde(default)]
     pub format: EmmyrcReformat,
+    #[serde(default)]
+    pub folding: EmmyrcFolding,
+    #[serde(default)]
+    pub compile_commands: EmmyrcCompileCommands,
 }
 
 impl Emmyrc {
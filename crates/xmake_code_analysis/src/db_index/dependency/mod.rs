@@ -1,6 +1,9 @@
 mod file_dependency_relation;
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
 use file_dependency_relation::FileDependencyRelation;
 
@@ -11,12 +14,15 @@ use super::LuaIndex;
 #[derive(Debug)]
 pub struct LuaDependencyIndex {
     dependencies: HashMap<FileId, HashSet<FileId>>,
+    /// `includes` of a file whose target is not indexed yet
+    unresolved_includes: HashMap<FileId, HashSet<PathBuf>>,
 }
 
 impl LuaDependencyIndex {
     pub fn new() -> Self {
         Self {
             dependencies: HashMap::new(),
+            unresolved_includes: HashMap::new(),
         }
     }
 
@@ -31,6 +37,22 @@ impl LuaDependencyIndex {
         self.dependencies.get(file_id)
     }
 
+    pub fn add_unresolved_include(&mut self, file_id: FileId, include_path: PathBuf) {
+        self.unresolved_includes
+            .entry(file_id)
+            .or_default()
+            .insert(include_path);
+    }
+
+    /// Files with an unresolved `includes` of `path`
+    pub fn get_unresolved_includers(&self, path: &Path) -> Vec<FileId> {
+        self.unresolved_includes
+            .iter()
+            .filter(|(_, include_paths)| include_paths.contains(path))
+            .map(|(file_id, _)| *file_id)
+            .collect()
+    }
+
     pub fn get_file_dependencies<'a>(&'a self) -> FileDependencyRelation<'a> {
         FileDependencyRelation::new(&self.dependencies)
     }
@@ -39,9 +61,11 @@ impl LuaDependencyIndex {
 impl LuaIndex for LuaDependencyIndex {
     fn remove(&mut self, file_id: FileId) {
         self.dependencies.remove(&file_id);
+        self.unresolved_includes.remove(&file_id);
     }
 
     fn clear(&mut self) {
         self.dependencies.clear();
+        self.unresolved_includes.clear();
     }
 }
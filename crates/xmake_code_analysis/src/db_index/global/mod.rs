@@ -111,6 +111,7 @@ pub fn filter_global_by_scope(
                 (XmakeScope::Rule, x) if !x.is_rule() => return Some(()),
                 (XmakeScope::Target, x) if !x.is_target() => return Some(()),
                 (XmakeScope::Task, x) if !x.is_task() => return Some(()),
+                (XmakeScope::Toolchain, x) if !x.is_toolchain() => return Some(()),
                 _ => {}
             }
         }
@@ -312,25 +312,35 @@ impl LuaModuleIndex {
                 self.exact_find_module(&parts, self.include_root_id)
             }
             _ => {
-                let current_path = db.get_vfs().get_file_path(&source_file_id)?;
-                let current_dir = Path::new(&current_path).parent()?;
-                let module_file_path = module_parts.join("/");
-                let target_path = if module_file_path.ends_with(".lua") {
-                    current_dir.join(&module_file_path)
-                } else {
-                    current_dir.join(&format!("{}/xmake.lua", module_file_path))
-                };
-                if target_path.exists() {
-                    let uri = file_path_to_uri(&target_path)?;
-                    let target_file_id = db.get_vfs().get_file_id(&uri)?;
-                    return self.file_module_map.get(&target_file_id);
-                }
-
-                None
+                // the vfs knows unsaved and removed files, not the file system
+                let target_path = self.get_include_path(db, module_path, source_file_id)?;
+                let uri = file_path_to_uri(&target_path)?;
+                let target_file_id = db.get_vfs().get_file_id(&uri)?;
+                self.file_module_map.get(&target_file_id)
             }
         }
     }
 
+    /// The file an `includes` path of `source_file_id` points to, whether it exists or not
+    pub fn get_include_path(
+        &self,
+        db: &DbIndex,
+        module_path: &str,
+        source_file_id: FileId,
+    ) -> Option<PathBuf> {
+        if module_path.starts_with("@builtin") {
+            return None;
+        }
+
+        let current_path = db.get_vfs().get_file_path(&source_file_id)?;
+        let current_dir = Path::new(&current_path).parent()?;
+        if module_path.ends_with(".lua") {
+            Some(current_dir.join(module_path))
+        } else {
+            Some(current_dir.join(format!("{}/xmake.lua", module_path)))
+        }
+    }
+
     fn exact_find_module(
         &self,
         module_parts: &Vec<&str>,
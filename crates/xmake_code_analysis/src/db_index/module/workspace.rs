@@ -25,6 +25,7 @@ impl WorkspaceId {
     pub const TEST: WorkspaceId = WorkspaceId { id: 60000 };
     pub const BUILTIN_IMPORT: WorkspaceId = WorkspaceId { id: 65000 };
     pub const BUILTIN_INCLUDE: WorkspaceId = WorkspaceId { id: 65001 };
+    pub const BUILTIN_TOOLCHAIN: WorkspaceId = WorkspaceId { id: 65002 };
 
     pub fn is_library(&self) -> bool {
         self.id > 1 && self.id < Self::TEST.id
@@ -46,6 +47,10 @@ impl WorkspaceId {
         self.id == Self::BUILTIN_INCLUDE.id
     }
 
+    pub fn is_toolchain(&self) -> bool {
+        self.id == Self::BUILTIN_TOOLCHAIN.id
+    }
+
     pub fn is_test(&self) -> bool {
         self.id == Self::TEST.id
     }
@@ -69,6 +74,7 @@ impl fmt::Display for WorkspaceId {
             60000 => write!(f, "test"),
             65000 => write!(f, "import"),
             65001 => write!(f, "include"),
+            65002 => write!(f, "toolchain"),
             _ => write!(f, "lib{}", self.id - 1),
         }
     }
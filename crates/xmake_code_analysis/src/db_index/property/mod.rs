@@ -2,7 +2,9 @@ mod property;
 
 use std::collections::{HashMap, HashSet};
 
-use emmylua_parser::{LuaAstNode, LuaDocTagField, LuaDocType, LuaVersionCondition, VisibilityKind};
+use emmylua_parser::{
+    LuaAstNode, LuaDocTagField, LuaDocType, LuaVersionCondition, LuaVersionNumber, VisibilityKind,
+};
 pub use property::*;
 
 use crate::{DbIndex, FileId, LuaMember, LuaSignatureId};
@@ -224,6 +226,23 @@ impl LuaPropertyIndex {
         Some(())
     }
 
+    pub fn add_since(
+        &mut self,
+        file_id: FileId,
+        owner_id: LuaSemanticDeclId,
+        since: LuaVersionNumber,
+    ) -> Option<()> {
+        let (property, _) = self.get_or_create_property(owner_id.clone())?;
+        property.add_since(since);
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_default()
+            .insert(owner_id);
+
+        Some(())
+    }
+
     pub fn get_property(&self, owner_id: &LuaSemanticDeclId) -> Option<&LuaCommonProperty> {
         self.property_owners_map
             .get(&owner_id)
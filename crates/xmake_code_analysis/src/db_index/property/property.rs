@@ -1,4 +1,4 @@
-use emmylua_parser::{LuaVersionCondition, VisibilityKind};
+use emmylua_parser::{LuaVersionCondition, LuaVersionNumber, VisibilityKind};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LuaCommonProperty {
@@ -10,6 +10,8 @@ pub struct LuaCommonProperty {
     pub tag_content: Option<Box<LuaTagContent>>,
     pub export: Option<LuaExport>,
     pub scope: Option<XmakeScope>,
+    /// xmake version that introduced the api, from `---@since`
+    pub since: Option<LuaVersionNumber>,
 }
 
 impl LuaCommonProperty {
@@ -23,6 +25,7 @@ impl LuaCommonProperty {
             tag_content: None,
             export: None,
             scope: None,
+            since: None,
         }
     }
 
@@ -82,6 +85,10 @@ impl LuaCommonProperty {
     pub fn add_scope(&mut self, scope: XmakeScope) {
         self.scope = Some(scope);
     }
+
+    pub fn add_since(&mut self, since: LuaVersionNumber) {
+        self.since = Some(since);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -122,6 +129,7 @@ pub enum XmakeScope {
     Option,
     Rule,
     Task,
+    Toolchain,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,180 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use lsp_types::{Location, Uri};
+use serde::{Deserialize, Serialize};
+
+use crate::{DbIndex, FileId};
+
+use super::{XmakeTarget, XmakeTargetKind, XmakeTargetValue};
+
+/// What a target depends on, from `add_deps`, `add_packages` and `add_rules`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum XmakeDependencyKind {
+    Target,
+    Package,
+    Rule,
+}
+
+impl XmakeDependencyKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            XmakeDependencyKind::Target => "target",
+            XmakeDependencyKind::Package => "package",
+            XmakeDependencyKind::Rule => "rule",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeDependencyNode {
+    /// Unique in the graph, `kind:name`
+    pub id: String,
+    pub name: String,
+    pub kind: XmakeDependencyKind,
+    /// Where the node is declared, `None` for packages from a repository or builtin rules
+    pub location: Option<Location>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeDependencyEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: XmakeDependencyKind,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeDependencyGraph {
+    pub nodes: Vec<XmakeDependencyNode>,
+    pub edges: Vec<XmakeDependencyEdge>,
+}
+
+impl XmakeDependencyGraph {
+    /// The graph of the targets declared in `file_ids`, or in every main workspace file when
+    /// `None`, the std and builtin targets and toolchains are left out
+    pub fn build(db: &DbIndex, file_ids: Option<&HashSet<FileId>>) -> Self {
+        let xmake_index = db.get_xmake_index();
+        let module_index = db.get_module_index();
+        let mut declarations: Vec<(FileId, &XmakeTarget)> = xmake_index
+            .get_all_targets()
+            .filter(|(file_id, _)| match file_ids {
+                Some(file_ids) => file_ids.contains(file_id),
+                None => module_index.is_main(file_id),
+            })
+            .collect();
+        declarations.sort_by(|(a_file, a), (b_file, b)| {
+            a_file
+                .partial_cmp(b_file)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.name_range.start().cmp(&b.name_range.start()))
+        });
+
+        let mut nodes: BTreeMap<String, XmakeDependencyNode> = BTreeMap::new();
+        let mut edges = BTreeSet::new();
+        for (file_id, target) in &declarations {
+            let kind = match target.kind {
+                XmakeTargetKind::Target => XmakeDependencyKind::Target,
+                XmakeTargetKind::Package => XmakeDependencyKind::Package,
+                XmakeTargetKind::Rule => XmakeDependencyKind::Rule,
+                _ => continue,
+            };
+            // the first declaration wins, others are usually `is_plat` branches
+            let location = get_location(db, *file_id, target);
+            let node = add_node(&mut nodes, kind, &target.name);
+            if node.location.is_none() {
+                node.location = location;
+            }
+            if !target.kind.is_target() {
+                continue;
+            }
+
+            let from = node.id.clone();
+            let properties = &target.properties;
+            let dependencies: [(XmakeDependencyKind, &Vec<XmakeTargetValue>); 3] = [
+                (XmakeDependencyKind::Target, &properties.deps),
+                (XmakeDependencyKind::Package, &properties.packages),
+                (XmakeDependencyKind::Rule, &properties.rules),
+            ];
+            for (kind, values) in dependencies {
+                for value in values {
+                    let to = add_node(&mut nodes, kind, &value.value).id.clone();
+                    edges.insert(XmakeDependencyEdge {
+                        from: from.clone(),
+                        to,
+                        kind,
+                    });
+                }
+            }
+        }
+
+        Self {
+            nodes: nodes.into_values().collect(),
+            edges: edges.into_iter().collect(),
+        }
+    }
+
+    /// Graphviz DOT, targets are boxes, packages ellipses and rules diamonds
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph xmake {\n    rankdir=LR;\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                XmakeDependencyKind::Target => "box",
+                XmakeDependencyKind::Package => "ellipse",
+                XmakeDependencyKind::Rule => "diamond",
+            };
+            dot.push_str(&format!(
+                "    {} [label={}, shape={}];\n",
+                quote_dot_id(&node.id),
+                quote_dot_id(&node.name),
+                shape
+            ));
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                XmakeDependencyKind::Target => "",
+                XmakeDependencyKind::Package => " [style=dashed]",
+                XmakeDependencyKind::Rule => " [style=dotted]",
+            };
+            dot.push_str(&format!(
+                "    {} -> {}{};\n",
+                quote_dot_id(&edge.from),
+                quote_dot_id(&edge.to),
+                style
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn add_node<'a>(
+    nodes: &'a mut BTreeMap<String, XmakeDependencyNode>,
+    kind: XmakeDependencyKind,
+    name: &str,
+) -> &'a mut XmakeDependencyNode {
+    let id = format!("{}:{}", kind.get_name(), name);
+    nodes
+        .entry(id.clone())
+        .or_insert_with(|| XmakeDependencyNode {
+            id,
+            name: name.to_string(),
+            kind,
+            location: None,
+        })
+}
+
+fn get_location(db: &DbIndex, file_id: FileId, target: &XmakeTarget) -> Option<Location> {
+    let vfs = db.get_vfs();
+    let uri: Uri = vfs.get_uri(&file_id)?;
+    let range = vfs
+        .get_document(&file_id)?
+        .to_lsp_range(target.name_range)?;
+    Some(Location { uri, range })
+}
+
+fn quote_dot_id(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
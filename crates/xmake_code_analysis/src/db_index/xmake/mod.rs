@@ -1,24 +1,103 @@
+mod dependency_graph;
 mod target;
+mod task;
+mod toolchain;
 mod xmake_function;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::{FileId, LuaIndex};
+use emmylua_parser::LuaVersionNumber;
+
+use crate::{DbIndex, FileId, LuaIndex, LuaSemanticDeclId, LuaType};
+pub use dependency_graph::*;
 pub use target::*;
+pub use task::*;
+pub use toolchain::*;
 pub use xmake_function::*;
 
 #[derive(Debug)]
 pub struct LuaXmakeIndex {
     includes_file_ids: HashMap<FileId, Vec<FileId>>,
+    /// The reverse of `includes_file_ids`, the files including each file
+    included_by_file_ids: HashMap<FileId, Vec<FileId>>,
     targets_or_packages: HashMap<FileId, Vec<XmakeTarget>>,
+    /// `set_xmakever` of each file
+    required_versions: HashMap<FileId, LuaVersionNumber>,
+    /// Version of the installed xmake, not tied to any file
+    xmake_version: Option<LuaVersionNumber>,
+}
+
+/// Why an xmake api can not be used
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XmakeVersionMismatch {
+    /// The installed xmake is older than the api
+    Installed(LuaVersionNumber),
+    /// The project supports xmake versions older than the api (`set_xmakever`)
+    Required(LuaVersionNumber),
 }
 
 impl LuaXmakeIndex {
     pub fn new() -> Self {
         Self {
             includes_file_ids: HashMap::new(),
+            included_by_file_ids: HashMap::new(),
             targets_or_packages: HashMap::new(),
+            required_versions: HashMap::new(),
+            xmake_version: None,
+        }
+    }
+
+    pub fn set_xmake_version(&mut self, version: LuaVersionNumber) {
+        self.xmake_version = Some(version);
+    }
+
+    pub fn get_xmake_version(&self) -> Option<&LuaVersionNumber> {
+        self.xmake_version.as_ref()
+    }
+
+    pub fn add_required_version(&mut self, file_id: FileId, version: LuaVersionNumber) {
+        self.required_versions.insert(file_id, version);
+    }
+
+    /// The `set_xmakever` that applies to `file_id`, declared in the file itself or in a file
+    /// including it.
+    pub fn get_required_version(&self, file_id: FileId) -> Option<LuaVersionNumber> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![file_id];
+        while let Some(file_id) = stack.pop() {
+            if !visited.insert(file_id) {
+                continue;
+            }
+            if let Some(version) = self.required_versions.get(&file_id) {
+                return Some(version.clone());
+            }
+            if let Some(includers) = self.included_by_file_ids.get(&file_id) {
+                stack.extend(includers);
+            }
+        }
+
+        None
+    }
+
+    /// Check an api introduced in `since` against the installed xmake and the project's
+    /// `set_xmakever`.
+    pub fn check_api_version(
+        &self,
+        file_id: FileId,
+        since: &LuaVersionNumber,
+    ) -> Option<XmakeVersionMismatch> {
+        if let Some(installed) = &self.xmake_version
+            && installed < since
+        {
+            return Some(XmakeVersionMismatch::Installed(installed.clone()));
+        }
+        if let Some(required) = self.get_required_version(file_id)
+            && required < *since
+        {
+            return Some(XmakeVersionMismatch::Required(required));
         }
+
+        None
     }
 
     pub fn add_includes(&mut self, file_id: FileId, include_file_id: FileId) {
@@ -26,12 +105,40 @@ impl LuaXmakeIndex {
             .entry(file_id)
             .or_insert_with(Vec::new)
             .push(include_file_id);
+        self.included_by_file_ids
+            .entry(include_file_id)
+            .or_default()
+            .push(file_id);
     }
 
     pub fn get_includes(&self, file_id: FileId) -> Option<&Vec<FileId>> {
         self.includes_file_ids.get(&file_id)
     }
 
+    /// Files with an `includes` of `file_id`
+    pub fn get_includers(&self, file_id: FileId) -> Option<&Vec<FileId>> {
+        self.included_by_file_ids.get(&file_id)
+    }
+
+    /// Files connected to `file_id` through `includes`, in either direction
+    pub fn get_project_files(&self, file_id: FileId) -> HashSet<FileId> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![file_id];
+        while let Some(file_id) = stack.pop() {
+            if !visited.insert(file_id) {
+                continue;
+            }
+            if let Some(includes) = self.includes_file_ids.get(&file_id) {
+                stack.extend(includes);
+            }
+            if let Some(includers) = self.included_by_file_ids.get(&file_id) {
+                stack.extend(includers);
+            }
+        }
+
+        visited
+    }
+
     pub fn add_target_or_package(&mut self, file_id: FileId, target: XmakeTarget) {
         self.targets_or_packages
             .entry(file_id)
@@ -42,16 +149,77 @@ impl LuaXmakeIndex {
     pub fn get_targets(&self, file_id: FileId) -> Option<&Vec<XmakeTarget>> {
         self.targets_or_packages.get(&file_id)
     }
+
+    /// Targets, packages, rules, options and tasks declared in any file
+    pub fn get_all_targets(&self) -> impl Iterator<Item = (FileId, &XmakeTarget)> {
+        self.targets_or_packages
+            .iter()
+            .flat_map(|(file_id, targets)| targets.iter().map(|target| (*file_id, target)))
+    }
+
+    /// Declarations of `name` with the given kind, there can be more than one with `is_plat` branches
+    pub fn find_targets<'a>(
+        &'a self,
+        name: &'a str,
+        kind: XmakeTargetKind,
+    ) -> impl Iterator<Item = (FileId, &'a XmakeTarget)> {
+        self.get_all_targets()
+            .filter(move |(_, target)| target.kind == kind && target.name == name)
+    }
 }
 
 impl LuaIndex for LuaXmakeIndex {
     fn remove(&mut self, file_id: crate::FileId) {
-        self.includes_file_ids.remove(&file_id);
+        // other files keep including this one, only the edges from it are dropped
+        for include in self.includes_file_ids.remove(&file_id).unwrap_or_default() {
+            if let Some(includers) = self.included_by_file_ids.get_mut(&include) {
+                includers.retain(|includer| *includer != file_id);
+            }
+        }
         self.targets_or_packages.remove(&file_id);
+        self.required_versions.remove(&file_id);
     }
 
     fn clear(&mut self) {
         self.includes_file_ids.clear();
+        self.included_by_file_ids.clear();
         self.targets_or_packages.clear();
+        self.required_versions.clear();
     }
 }
+
+/// The `---@since` version of a function type
+pub fn get_xmake_api_since(db: &DbIndex, typ: &LuaType) -> Option<LuaVersionNumber> {
+    let LuaType::Signature(signature_id) = typ else {
+        return None;
+    };
+
+    let semantic_id = LuaSemanticDeclId::Signature(*signature_id);
+    db.get_property_index()
+        .get_property(&semantic_id)?
+        .since
+        .clone()
+}
+
+/// Parse versions like `2.8.0` or `v2.8`, unlike `LuaVersionNumber::from_str` this rejects
+/// anything that is not a version.
+pub fn parse_xmake_version(text: &str) -> Option<LuaVersionNumber> {
+    let text = text.trim();
+    let text = text.strip_prefix('v').unwrap_or(text);
+    let mut parts = text.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = match parts.next() {
+        Some(patch) => patch.parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(LuaVersionNumber::new(major, minor, patch))
+}
+
+pub fn format_xmake_version(version: &LuaVersionNumber) -> String {
+    format!("{}.{}.{}", version.major, version.minor, version.patch)
+}
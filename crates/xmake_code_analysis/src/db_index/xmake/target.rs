@@ -1,10 +1,231 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallArgList, LuaCallExpr, LuaExpr, LuaIndexKey, LuaLiteralExpr,
+    LuaStringToken, LuaTableExpr, LuaTableField,
+};
 use rowan::TextRange;
 
+use crate::{DbIndex, LuaSemanticDeclId, LuaSignatureId, LuaType, XmakeScope};
+
+use super::{XmakeFunction, get_xmake_function};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct XmakeTarget {
     pub name: String,
     pub kind: XmakeTargetKind,
+    /// Range of the scope body, from the declaration to the end of the scope
     pub range: TextRange,
+    /// Range of the name string in the declaration call
+    pub name_range: TextRange,
+    /// Range of the `target_end()`-like call closing the scope, `None` when the scope ends
+    /// implicitly at the next declaration or at the end of the file
+    pub end_call_range: Option<TextRange>,
+    /// What the description apis in the scope body set
+    pub properties: XmakeTargetProperties,
+}
+
+/// A string argument of a description api, e.g. `"core"` in `add_deps("core")`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XmakeTargetValue {
+    pub value: String,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct XmakeTargetProperties {
+    /// `set_kind`
+    pub kind: Option<XmakeTargetValue>,
+    /// `add_deps`
+    pub deps: Vec<XmakeTargetValue>,
+    /// `add_packages`
+    pub packages: Vec<XmakeTargetValue>,
+    /// `add_rules`
+    pub rules: Vec<XmakeTargetValue>,
+    /// `add_options`
+    pub options: Vec<XmakeTargetValue>,
+    /// `add_files`
+    pub files: Vec<XmakeTargetValue>,
+    /// `add_defines`
+    pub defines: Vec<XmakeTargetValue>,
+    /// `set_description`
+    pub description: Option<XmakeTargetValue>,
+    /// `set_menu`, only tasks with a menu can be run from the command line
+    pub has_menu: bool,
+    /// `options` of the task menu, `set_menu {options = {{"k", "key", "kv", nil, "The key"}}}`
+    pub menu_options: Vec<XmakeMenuOption>,
+    /// `on_run("main")`, the script file of a task relative to the file declaring it
+    pub run_script: Option<XmakeTargetValue>,
+}
+
+/// An option of a task menu, `{short, long, kind, default, description, values...}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XmakeMenuOption {
+    /// e.g. `k` for `-k`
+    pub short: Option<String>,
+    /// The long name, the key of `option.get("key")`
+    pub name: XmakeTargetValue,
+    /// `k` for a flag, `kv` for a key with a value, `v` and `vs` for one or more positional values
+    pub kind: String,
+    pub description: Option<String>,
+}
+
+impl XmakeMenuOption {
+    /// How the option is passed on the command line, e.g. `-k KEY`, `--key=KEY` or `files ...`
+    pub fn get_usage(&self) -> String {
+        let name = &self.name.value;
+        let value = name.to_uppercase();
+        match (self.kind.as_str(), &self.short) {
+            ("k", Some(short)) => format!("-{}", short),
+            ("k", None) => format!("--{}", name),
+            ("kv", Some(short)) => format!("-{} {}", short, value),
+            ("kv", None) => format!("--{}={}", name, value),
+            ("vs", _) => format!("{} ...", name),
+            _ => name.clone(),
+        }
+    }
+}
+
+impl XmakeTargetProperties {
+    /// Record the string arguments of a call to the description api `name`
+    pub fn add(&mut self, name: &str, values: Vec<XmakeTargetValue>) {
+        let list = match name {
+            "set_kind" => {
+                self.kind = values.into_iter().next();
+                return;
+            }
+            "set_description" => {
+                self.description = values.into_iter().next();
+                return;
+            }
+            "on_run" => {
+                if let Some(value) = values.into_iter().next() {
+                    self.run_script = Some(value);
+                }
+                return;
+            }
+            "add_deps" => &mut self.deps,
+            "add_packages" => &mut self.packages,
+            "add_rules" => &mut self.rules,
+            "add_options" => &mut self.options,
+            "add_files" => &mut self.files,
+            "add_defines" => &mut self.defines,
+            _ => return,
+        };
+        list.extend(values);
+    }
+
+    /// Record a field of the table form, `target("app", {kind = "binary", deps = {"core"}})`
+    pub fn add_field(&mut self, field: &str, values: Vec<XmakeTargetValue>) {
+        self.add(&get_attr_api(field), values);
+    }
+}
+
+/// The api a field of the table form calls, only the apis of `XmakeTargetProperties` that are
+/// not `add_*` are listed
+fn get_attr_api(field: &str) -> String {
+    match field {
+        "kind" | "description" => format!("set_{}", field),
+        _ => format!("add_{}", field),
+    }
+}
+
+/// The description api a string literal is passed to, and the call it is an argument of. Fields
+/// of the table form stand for their api, so this is `add_deps` for both `add_deps("core")` and
+/// `target("app", {deps = "core"})`.
+pub fn get_string_api(string_token: &LuaStringToken) -> Option<(String, LuaCallExpr)> {
+    let literal_expr = string_token.get_parent::<LuaLiteralExpr>()?;
+    let parent = literal_expr.syntax().parent()?;
+    if let Some(arg_list) = LuaCallArgList::cast(parent.clone()) {
+        let call_expr = arg_list.get_parent::<LuaCallExpr>()?;
+        let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+            return None;
+        };
+        return Some((name_expr.get_name_text()?, call_expr));
+    }
+
+    // `deps = "core"` or `deps = {"core"}`
+    let mut field = LuaTableField::cast(parent)?;
+    if field.is_value_field() {
+        field = field
+            .get_parent::<LuaTableExpr>()?
+            .get_parent::<LuaTableField>()?;
+    }
+    let LuaIndexKey::Name(name) = field.get_field_key()? else {
+        return None;
+    };
+    let table_expr = field.get_parent::<LuaTableExpr>()?;
+    let call_expr = get_target_attr_call(&table_expr)?;
+    Some((get_attr_api(name.get_name_text()), call_expr))
+}
+
+/// Global interfaces of the std that xmake also accepts in a target, `set_version` sets the
+/// version of the target there
+const TARGET_GLOBAL_APIS: &[&str] = &["set_version"];
+
+/// Fields of `target(name, attr)` and the api each one calls, read from the `set_*`, `add_*` and
+/// `on_*`/`before_*`/`after_*` apis of the target scope. xmake calls `set_{field}` or
+/// `add_{field}` for a field and `{field}` itself for a script.
+pub fn get_target_attr_apis(db: &DbIndex) -> HashMap<String, LuaSignatureId> {
+    let mut apis = HashMap::new();
+    for decl_id in db.get_global_index().get_all_global_decl_ids() {
+        let Some(decl) = db.get_decl_index().get_decl(&decl_id) else {
+            continue;
+        };
+        let name = decl.get_name();
+        let field = if let Some(field) = name
+            .strip_prefix("set_")
+            .or_else(|| name.strip_prefix("add_"))
+        {
+            field
+        } else if ["on_", "before_", "after_"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        {
+            name
+        } else {
+            continue;
+        };
+
+        let Some(type_cache) = db.get_type_index().get_type_cache(&decl_id.into()) else {
+            continue;
+        };
+        let LuaType::Signature(signature_id) = type_cache.as_type() else {
+            continue;
+        };
+        let scope = db
+            .get_property_index()
+            .get_property(&LuaSemanticDeclId::Signature(*signature_id))
+            .and_then(|property| property.scope);
+        let is_target_api = match scope {
+            Some(scope) => scope == XmakeScope::Target,
+            None => {
+                TARGET_GLOBAL_APIS.contains(&name)
+                    && db.get_module_index().is_meta_file(&decl_id.file_id)
+            }
+        };
+        if is_target_api {
+            // `set_*` wins over `add_*` like in xmake
+            if name.starts_with("set_") || !apis.contains_key(field) {
+                apis.insert(field.to_string(), *signature_id);
+            }
+        }
+    }
+    apis
+}
+
+/// The `target(name, attr)` call a table is the attributes of
+pub fn get_target_attr_call(table_expr: &LuaTableExpr) -> Option<LuaCallExpr> {
+    let arg_list = table_expr.get_parent::<LuaCallArgList>()?;
+    let call_expr = arg_list.get_parent::<LuaCallExpr>()?;
+    if get_xmake_function(&call_expr)? != XmakeFunction::Target {
+        return None;
+    }
+    let is_second_arg = arg_list
+        .get_args()
+        .nth(1)
+        .is_some_and(|arg| arg.syntax() == table_expr.syntax());
+    is_second_arg.then_some(call_expr)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
@@ -14,6 +235,7 @@ pub enum XmakeTargetKind {
     Rule,
     Option,
     Task,
+    Toolchain,
 }
 
 impl XmakeTargetKind {
@@ -24,10 +246,23 @@ impl XmakeTargetKind {
             "rule" => Some(XmakeTargetKind::Rule),
             "option" => Some(XmakeTargetKind::Option),
             "task" => Some(XmakeTargetKind::Task),
+            "toolchain" => Some(XmakeTargetKind::Toolchain),
             _ => None,
         }
     }
 
+    /// Name of the declaring function, the inverse of `parse`
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            XmakeTargetKind::Target => "target",
+            XmakeTargetKind::Package => "package",
+            XmakeTargetKind::Rule => "rule",
+            XmakeTargetKind::Option => "option",
+            XmakeTargetKind::Task => "task",
+            XmakeTargetKind::Toolchain => "toolchain",
+        }
+    }
+
     pub fn is_target(&self) -> bool {
         matches!(self, XmakeTargetKind::Target)
     }
@@ -47,4 +282,8 @@ impl XmakeTargetKind {
     pub fn is_task(&self) -> bool {
         matches!(self, XmakeTargetKind::Task)
     }
+
+    pub fn is_toolchain(&self) -> bool {
+        matches!(self, XmakeTargetKind::Toolchain)
+    }
 }
@@ -0,0 +1,106 @@
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallArgList, LuaCallExpr, LuaClosureExpr, LuaExpr, LuaLiteralExpr,
+    LuaLiteralToken, LuaStringToken, LuaSyntaxNode,
+};
+
+use crate::{DbIndex, FileId, XmakeTarget, XmakeTargetKind};
+
+/// Options every task accepts besides its menu, from `task.common_options` of xmake
+const COMMON_TASK_OPTIONS: &[&str] = &[
+    "quiet",
+    "yes",
+    "confirm",
+    "verbose",
+    "root",
+    "diagnosis",
+    "version",
+    "help",
+    "file",
+    "project",
+];
+
+pub fn is_common_task_option(name: &str) -> bool {
+    COMMON_TASK_OPTIONS.contains(&name)
+}
+
+/// The key of `option.get("key")`
+pub fn get_option_get_key(call_expr: &LuaCallExpr) -> Option<LuaStringToken> {
+    let LuaExpr::IndexExpr(index_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    let LuaExpr::NameExpr(prefix) = index_expr.get_prefix_expr()? else {
+        return None;
+    };
+    if prefix.get_name_text()? != "option" || index_expr.get_index_name_token()?.text() != "get" {
+        return None;
+    }
+
+    let LuaExpr::LiteralExpr(literal) = call_expr.get_args_list()?.get_args().next()? else {
+        return None;
+    };
+    match literal.get_literal()? {
+        LuaLiteralToken::String(string_token) => Some(string_token),
+        _ => None,
+    }
+}
+
+/// The `option.get` call `string_token` is the key of
+pub fn get_option_get_call(string_token: &LuaStringToken) -> Option<LuaCallExpr> {
+    let call_expr = string_token
+        .get_parent::<LuaLiteralExpr>()?
+        .get_parent::<LuaCallArgList>()?
+        .get_parent::<LuaCallExpr>()?;
+    let key = get_option_get_key(&call_expr)?;
+    (key.get_range() == string_token.get_range()).then_some(call_expr)
+}
+
+/// The task a script runs for, either from a function passed to `on_run` in the task or from
+/// the script file of `on_run("main")`
+pub fn find_running_task<'a>(
+    db: &'a DbIndex,
+    file_id: FileId,
+    node: &LuaSyntaxNode,
+) -> Option<&'a XmakeTarget> {
+    let xmake_index = db.get_xmake_index();
+    for closure in node.ancestors().filter_map(LuaClosureExpr::cast) {
+        let Some(call_expr) = closure
+            .get_parent::<LuaCallArgList>()
+            .and_then(|arg_list| arg_list.get_parent::<LuaCallExpr>())
+        else {
+            continue;
+        };
+        let Some(LuaExpr::NameExpr(name_expr)) = call_expr.get_prefix_expr() else {
+            continue;
+        };
+        if name_expr.get_name_text().as_deref() != Some("on_run") {
+            continue;
+        }
+        let position = call_expr.get_position();
+        return xmake_index.get_targets(file_id)?.iter().find(|target| {
+            target.kind == XmakeTargetKind::Task && target.range.contains(position)
+        });
+    }
+
+    let vfs = db.get_vfs();
+    let file_path = vfs.get_file_path(&file_id)?;
+    xmake_index
+        .get_all_targets()
+        .find(|(task_file_id, target)| {
+            let Some(script) = &target.properties.run_script else {
+                return false;
+            };
+            vfs.get_file_path(task_file_id)
+                .and_then(|task_path| task_path.parent())
+                .is_some_and(|dir| dir.join(format!("{}.lua", script.value)) == *file_path)
+        })
+        .map(|(_, target)| target)
+}
+
+/// Command line usage of a task, e.g. `xmake mytask [-k KEY] [files ...]`
+pub fn get_task_usage(task: &XmakeTarget) -> String {
+    let mut usage = format!("xmake {}", task.name);
+    for option in &task.properties.menu_options {
+        usage.push_str(&format!(" [{}]", option.get_usage()));
+    }
+    usage
+}
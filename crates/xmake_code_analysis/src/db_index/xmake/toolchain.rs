@@ -0,0 +1,95 @@
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaExpr, LuaLiteralToken, LuaStringToken};
+
+use crate::{DbIndex, FileId, XmakeTarget, XmakeTargetKind, get_string_api};
+
+/// How a string argument refers to a toolchain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XmakeToolchainArg {
+    /// `set_toolchains("clang")`, `add_toolchains("clang")` or `is_config("toolchain", "clang")`
+    Toolchain,
+    /// `is_config("toolchain", "clang.*")`, values of `is_config` are also Lua patterns
+    Pattern,
+    /// `set_toolset("cc", "clang")` names a tool, which is often but not always a toolchain
+    Tool,
+}
+
+const LUA_PATTERN_CHARS: &[char] = &['^', '$', '(', ')', '%', '.', '[', ']', '*', '+', '-', '?'];
+
+/// Whether the string literal `string_token` names a toolchain
+pub fn get_toolchain_arg(string_token: &LuaStringToken) -> Option<XmakeToolchainArg> {
+    let (api, call_expr) = get_string_api(string_token)?;
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    let index = args
+        .iter()
+        .position(|arg| arg.get_range().contains_range(string_token.get_range()))?;
+    match api.as_str() {
+        // also the `toolchains` field of `target("app", {toolchains = "clang"})`
+        "set_toolchains" | "add_toolchains" => Some(XmakeToolchainArg::Toolchain),
+        "set_toolset" if index == 1 => Some(XmakeToolchainArg::Tool),
+        "is_config" if index > 0 && is_string_literal(&args[0], "toolchain") => {
+            if string_token.get_value().contains(LUA_PATTERN_CHARS) {
+                Some(XmakeToolchainArg::Pattern)
+            } else {
+                Some(XmakeToolchainArg::Toolchain)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_string_literal(expr: &LuaExpr, value: &str) -> bool {
+    let LuaExpr::LiteralExpr(literal) = expr else {
+        return false;
+    };
+    matches!(
+        literal.get_literal(),
+        Some(LuaLiteralToken::String(string_token)) if string_token.get_value() == value
+    )
+}
+
+/// Declarations of the toolchain `name` refers to, `gcc-11` falls back to `gcc` and `clang@llvm`
+/// to `clang`. `None` for toolchains of packages like `@muslcc`, which are not indexed.
+pub fn find_toolchains<'a>(
+    db: &'a DbIndex,
+    name: &'a str,
+) -> Option<Vec<(FileId, &'a XmakeTarget)>> {
+    let name = match name.split_once('@') {
+        Some(("", _)) => return None,
+        Some((name, _)) => name,
+        None => name,
+    };
+
+    let xmake_index = db.get_xmake_index();
+    let toolchains = xmake_index
+        .find_targets(name, XmakeTargetKind::Toolchain)
+        .collect::<Vec<_>>();
+    if !toolchains.is_empty() {
+        return Some(toolchains);
+    }
+
+    let Some((base_name, version)) = name.rsplit_once('-') else {
+        return Some(Vec::new());
+    };
+    if version.is_empty() || !version.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Some(Vec::new());
+    }
+    Some(
+        xmake_index
+            .find_targets(base_name, XmakeTargetKind::Toolchain)
+            .collect(),
+    )
+}
+
+/// Whether the toolchains of the xmake program dir are indexed, unknown names can only be
+/// reported then
+pub fn has_builtin_toolchains(db: &DbIndex) -> bool {
+    let module_index = db.get_module_index();
+    db.get_xmake_index()
+        .get_all_targets()
+        .any(|(file_id, target)| {
+            target.kind.is_toolchain()
+                && module_index
+                    .get_workspace_id(file_id)
+                    .is_some_and(|workspace_id| workspace_id.is_toolchain())
+        })
+}
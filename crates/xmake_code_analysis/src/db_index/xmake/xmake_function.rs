@@ -11,11 +11,14 @@ pub enum XmakeFunction {
     Option,
     Rule,
     Task,
+    Toolchain,
     EndTarget,
     EndPackage,
     EndOption,
     EndRule,
     EndTask,
+    EndToolchain,
+    SetXmakever,
 }
 
 pub fn get_xmake_function(call_expr: &LuaCallExpr) -> Option<XmakeFunction> {
@@ -24,22 +27,28 @@ pub fn get_xmake_function(call_expr: &LuaCallExpr) -> Option<XmakeFunction> {
         return None;
     };
 
-    let name = name_expr.get_name_text()?;
-    match name.as_str() {
+    get_xmake_function_by_name(&name_expr.get_name_text()?)
+}
+
+pub fn get_xmake_function_by_name(name: &str) -> Option<XmakeFunction> {
+    match name {
         "add_files" => Some(XmakeFunction::AddFiles),
         "includes" => Some(XmakeFunction::Includes),
         "import" => Some(XmakeFunction::Import),
         "add_deps" => Some(XmakeFunction::AddDeps),
         "target" => Some(XmakeFunction::Target),
-        "end_target" => Some(XmakeFunction::EndTarget),
+        "target_end" | "end_target" => Some(XmakeFunction::EndTarget),
         "package" => Some(XmakeFunction::Package),
-        "end_package" => Some(XmakeFunction::EndPackage),
+        "package_end" | "end_package" => Some(XmakeFunction::EndPackage),
         "option" => Some(XmakeFunction::Option),
-        "end_option" => Some(XmakeFunction::EndOption),
+        "option_end" | "end_option" => Some(XmakeFunction::EndOption),
         "rule" => Some(XmakeFunction::Rule),
-        "end_rule" => Some(XmakeFunction::EndRule),
+        "rule_end" | "end_rule" => Some(XmakeFunction::EndRule),
         "task" => Some(XmakeFunction::Task),
-        "end_task" => Some(XmakeFunction::EndTask),
-        _ => return None,
+        "task_end" | "end_task" => Some(XmakeFunction::EndTask),
+        "toolchain" => Some(XmakeFunction::Toolchain),
+        "toolchain_end" | "end_toolchain" => Some(XmakeFunction::EndToolchain),
+        "set_xmakever" => Some(XmakeFunction::SetXmakever),
+        _ => None,
     }
 }
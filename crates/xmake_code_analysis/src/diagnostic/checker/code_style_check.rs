@@ -1,3 +1,8 @@
+use emmylua_parser::LuaAst;
+use rowan::{TextRange, TextSize};
+use similar::{DiffTag, TextDiff};
+use xmake_formatter::reformat_node;
+
 use crate::{DiagnosticCode, SemanticModel};
 
 use super::{Checker, DiagnosticContext};
@@ -7,5 +12,67 @@ pub struct CodeStyleCheckChecker;
 impl Checker for CodeStyleCheckChecker {
     const CODES: &[DiagnosticCode] = &[DiagnosticCode::CodeStyleCheck];
 
-    fn check(_: &mut DiagnosticContext, _: &SemanticModel) {}
+    /// Reports each group of lines that differs from the formatter output, the expected text is
+    /// kept in the diagnostic data for the fix action
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        if semantic_model
+            .get_db()
+            .get_vfs()
+            .get_syntax_tree(&semantic_model.get_file_id())
+            .is_none_or(|tree| tree.has_syntax_errors())
+        {
+            return;
+        }
+
+        let document = semantic_model.get_document();
+        let text = document.get_text();
+        let formatted = reformat_node(&LuaAst::LuaChunk(semantic_model.get_root().clone()));
+        if formatted == text {
+            return;
+        }
+
+        let diff = TextDiff::from_lines(text, formatted.as_str());
+        let old_lines = diff.old_slices();
+        let new_lines = diff.new_slices();
+        let mut line_offsets = Vec::with_capacity(old_lines.len() + 1);
+        let mut offset = 0;
+        line_offsets.push(offset);
+        for line in old_lines {
+            offset += line.len();
+            line_offsets.push(offset);
+        }
+
+        for group in diff.grouped_ops(0) {
+            let (Some(first), Some(last)) = (group.first(), group.last()) else {
+                continue;
+            };
+            if group.iter().all(|op| op.tag() == DiffTag::Equal) {
+                continue;
+            }
+
+            let old_range = first.old_range().start..last.old_range().end;
+            let new_range = first.new_range().start..last.new_range().end;
+            let range = TextRange::new(
+                TextSize::from(line_offsets[old_range.start] as u32),
+                TextSize::from(line_offsets[old_range.end] as u32),
+            );
+            let expected = new_lines[new_range].concat();
+            let message = if expected.trim().is_empty() {
+                t!("Incorrect format, expected these lines to be removed").to_string()
+            } else {
+                t!(
+                    "Incorrect format, expected: %{expected}",
+                    expected = expected.trim_end()
+                )
+                .to_string()
+            };
+
+            context.add_diagnostic(
+                DiagnosticCode::CodeStyleCheck,
+                range,
+                message,
+                Some(serde_json::Value::String(expected)),
+            );
+        }
+    }
 }
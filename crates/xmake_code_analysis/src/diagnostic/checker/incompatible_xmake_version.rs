@@ -0,0 +1,65 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr};
+
+use crate::{
+    DiagnosticCode, SemanticModel, XmakeVersionMismatch, format_xmake_version, get_xmake_api_since,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct IncompatibleXmakeVersionChecker;
+
+impl Checker for IncompatibleXmakeVersionChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::IncompatibleXmakeVersion];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_call_expr(context, semantic_model, call_expr);
+        }
+    }
+}
+
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+
+    let typ = semantic_model
+        .infer_expr(LuaExpr::NameExpr(name_expr.clone()))
+        .ok()?;
+    let db = semantic_model.get_db();
+    let since = get_xmake_api_since(db, &typ)?;
+    let mismatch = db
+        .get_xmake_index()
+        .check_api_version(semantic_model.get_file_id(), &since)?;
+
+    let name = name_expr.get_name_text()?;
+    let since = format_xmake_version(&since);
+    let message = match mismatch {
+        XmakeVersionMismatch::Installed(version) => t!(
+            "`%{name}` requires xmake %{since}, but the installed xmake is %{version}",
+            name = name,
+            since = since,
+            version = format_xmake_version(&version)
+        ),
+        XmakeVersionMismatch::Required(version) => t!(
+            "`%{name}` requires xmake %{since}, but the project supports xmake %{version} (set_xmakever)",
+            name = name,
+            since = since,
+            version = format_xmake_version(&version)
+        ),
+    };
+
+    context.add_diagnostic(
+        DiagnosticCode::IncompatibleXmakeVersion,
+        name_expr.get_range(),
+        message.to_string(),
+        None,
+    );
+
+    Some(())
+}
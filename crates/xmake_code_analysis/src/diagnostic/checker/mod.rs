@@ -17,6 +17,7 @@ mod duplicate_require;
 mod duplicate_type;
 mod enum_value_mismatch;
 mod generic;
+mod incompatible_xmake_version;
 mod incomplete_signature_doc;
 mod local_const_reassign;
 mod missing_fields;
@@ -26,10 +27,13 @@ mod redefined_local;
 mod require_module_visibility;
 mod return_type_mismatch;
 mod syntax_error;
+mod target_attr;
 mod unbalanced_assignments;
 mod undefined_doc_param;
 mod undefined_global;
+mod undefined_toolchain;
 mod unknown_doc_tag;
+mod unknown_task_option;
 mod unnecessary_assert;
 mod unnecessary_if;
 mod unused;
@@ -109,6 +113,13 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<require_module_visibility::RequireModuleVisibilityChecker>(context, semantic_model);
     run_check::<unknown_doc_tag::UnknownDocTag>(context, semantic_model);
     run_check::<enum_value_mismatch::EnumValueMismatchChecker>(context, semantic_model);
+    run_check::<incompatible_xmake_version::IncompatibleXmakeVersionChecker>(
+        context,
+        semantic_model,
+    );
+    run_check::<undefined_toolchain::UndefinedToolchainChecker>(context, semantic_model);
+    run_check::<target_attr::TargetAttrChecker>(context, semantic_model);
+    run_check::<unknown_task_option::UnknownTaskOptionChecker>(context, semantic_model);
 
     run_check::<code_style::non_literal_expressions_in_assert::NonLiteralExpressionsInAssertChecker>(
         context,
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaIndexKey, LuaTableExpr};
+
+use crate::{
+    DiagnosticCode, LuaSignatureId, SemanticModel, get_target_attr_apis, get_target_attr_call,
+};
+
+use super::{Checker, DiagnosticContext};
+
+/// Fields of `target("app", {...})` are checked against the apis of the target scope, the
+/// overload of `target` taking a function hides the `TargetAttr` type
+pub struct TargetAttrChecker;
+
+impl Checker for TargetAttrChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UndefinedTargetAttr];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        let table_exprs = root
+            .descendants::<LuaTableExpr>()
+            .filter(|table_expr| get_target_attr_call(table_expr).is_some())
+            .collect::<Vec<_>>();
+        if table_exprs.is_empty() {
+            return;
+        }
+
+        let fields = get_target_attr_apis(semantic_model.get_db());
+        // without the std every field would be unknown
+        if fields.is_empty() {
+            return;
+        }
+        for table_expr in table_exprs {
+            check_table_expr(context, &fields, table_expr);
+        }
+    }
+}
+
+fn check_table_expr(
+    context: &mut DiagnosticContext,
+    fields: &HashMap<String, LuaSignatureId>,
+    table_expr: LuaTableExpr,
+) {
+    for field in table_expr.get_fields() {
+        let (name, range) = match field.get_field_key() {
+            Some(LuaIndexKey::Name(name)) => (name.get_name_text().to_string(), name.get_range()),
+            Some(LuaIndexKey::String(string)) => (string.get_value(), string.get_range()),
+            _ => continue,
+        };
+        if fields.contains_key(&name) {
+            continue;
+        }
+
+        context.add_diagnostic(
+            DiagnosticCode::UndefinedTargetAttr,
+            range,
+            t!(
+                "Undefined field `%{field}` of `%{class}`",
+                field = name,
+                class = "TargetAttr"
+            )
+            .to_string(),
+            None,
+        );
+    }
+}
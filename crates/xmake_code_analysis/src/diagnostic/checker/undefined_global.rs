@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaNameExpr};
+use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaExpr, LuaIndexExpr, LuaNameExpr};
 use rowan::TextRange;
 
 use crate::{DiagnosticCode, LuaSignatureId, SemanticModel};
@@ -16,12 +16,211 @@ impl Checker for UndefinedGlobalChecker {
         let root = semantic_model.get_root().clone();
         let mut use_range_set = HashSet::new();
         calc_name_expr_ref(semantic_model, &mut use_range_set);
+        let is_description_file = is_description_file(semantic_model);
         for name_expr in root.descendants::<LuaNameExpr>() {
-            check_name_expr(context, semantic_model, &mut use_range_set, name_expr);
+            let sandbox = if is_description_file && !is_in_function(&name_expr) {
+                XmakeSandbox::Description
+            } else {
+                XmakeSandbox::Script
+            };
+            check_name_expr(
+                context,
+                semantic_model,
+                &mut use_range_set,
+                name_expr,
+                sandbox,
+            );
         }
     }
 }
 
+/// xmake runs the description of a project and its scripts in different sandboxes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum XmakeSandbox {
+    /// `xmake.lua` and the files it includes, outside of functions
+    Description,
+    /// `on_load`/`on_build`/... functions and the modules they import, all builtins are there
+    Script,
+}
+
+/// Lua builtins kept in the description sandbox, on top of the xmake APIs
+const DESCRIPTION_BUILTINS: &[&str] = &[
+    "_G",
+    "_VERSION",
+    "assert",
+    "coroutine",
+    "error",
+    "getmetatable",
+    "io",
+    "ipairs",
+    "math",
+    "next",
+    "os",
+    "pairs",
+    "path",
+    "pcall",
+    "print",
+    "rawequal",
+    "rawget",
+    "rawlen",
+    "rawset",
+    "select",
+    "setmetatable",
+    "string",
+    "table",
+    "tonumber",
+    "tostring",
+    "type",
+    "unpack",
+    "xpcall",
+];
+
+/// Script only xmake APIs declared next to the description ones
+const SCRIPT_ONLY_APIS: &[&str] = &["import", "inherit"];
+
+/// The read-only part of `os` the description sandbox keeps
+const DESCRIPTION_OS_MEMBERS: &[&str] = &[
+    "arch",
+    "argv",
+    "clock",
+    "curdir",
+    "date",
+    "default_njob",
+    "dirs",
+    "exists",
+    "features",
+    "filedirs",
+    "files",
+    "getenv",
+    "getwinsize",
+    "host",
+    "is_arch",
+    "is_host",
+    "is_subarch",
+    "is_subhost",
+    "isdir",
+    "isfile",
+    "islink",
+    "mtime",
+    "programdir",
+    "programfile",
+    "projectdir",
+    "scriptdir",
+    "shell",
+    "subarch",
+    "subhost",
+    "term",
+    "time",
+    "tmpdir",
+];
+
+/// The reading part of `io` the description sandbox keeps, e.g. `io.readfile("version.txt")`
+const DESCRIPTION_IO_MEMBERS: &[&str] = &["lines", "load", "readfile"];
+
+/// The string helpers of `path` the description sandbox keeps, e.g. `path.join(dir, "src")`
+const DESCRIPTION_PATH_MEMBERS: &[&str] = &[
+    "absolute",
+    "basename",
+    "cygwin",
+    "directory",
+    "envsep",
+    "extension",
+    "filename",
+    "is_absolute",
+    "join",
+    "joinenv",
+    "new",
+    "normalize",
+    "pattern",
+    "relative",
+    "sep",
+    "split",
+    "splitenv",
+    "translate",
+    "unix",
+];
+
+/// The `table` functions of Lua and the xmake helpers the description sandbox keeps, without
+/// the LuaJIT `table.new`/`table.clear` extensions and the removed `foreach` functions
+const DESCRIPTION_TABLE_MEMBERS: &[&str] = &[
+    "append",
+    "concat",
+    "contains",
+    "copy",
+    "deepcopy",
+    "find",
+    "getn",
+    "ifind",
+    "inherit",
+    "insert",
+    "insertuniq",
+    "isarray",
+    "join",
+    "join2",
+    "keys",
+    "maxn",
+    "move",
+    "orderkeys",
+    "pack",
+    "remove",
+    "remove_if",
+    "sort",
+    "swap",
+    "unique",
+    "unpack",
+    "values",
+    "wrap",
+];
+
+/// `xmake.lua` files and the files they include describe the project
+fn is_description_file(semantic_model: &SemanticModel) -> bool {
+    let db = semantic_model.get_db();
+    let file_id = semantic_model.get_file_id();
+    let is_xmake_lua = db
+        .get_vfs()
+        .get_file_path(&file_id)
+        .and_then(|path| path.file_name())
+        .is_some_and(|name| name == "xmake.lua");
+    if is_xmake_lua {
+        return true;
+    }
+
+    db.get_xmake_index()
+        .get_includers(file_id)
+        .is_some_and(|includers| !includers.is_empty())
+}
+
+fn is_in_function(name_expr: &LuaNameExpr) -> bool {
+    name_expr.ancestors::<LuaClosureExpr>().next().is_some()
+}
+
+/// Whether the global `name` exists in the description sandbox: xmake APIs from `std/xmake`,
+/// globals of the project and the builtins the sandbox keeps
+fn is_description_global(semantic_model: &SemanticModel, name: &str) -> bool {
+    if DESCRIPTION_BUILTINS.contains(&name) {
+        return true;
+    }
+    if SCRIPT_ONLY_APIS.contains(&name) {
+        return false;
+    }
+
+    let db = semantic_model.get_db();
+    let Some(decl_ids) = db.get_global_index().get_global_decl_ids(name) else {
+        return false;
+    };
+    let module_index = db.get_module_index();
+    decl_ids
+        .iter()
+        .any(|decl_id| match module_index.get_module(decl_id.file_id) {
+            Some(module_info) if module_info.workspace_id.is_std() => {
+                module_info.full_module_name.starts_with("xmake.")
+            }
+            // modules of `import`
+            Some(module_info) if module_info.workspace_id.is_import() => false,
+            _ => true,
+        })
+}
+
 fn calc_name_expr_ref(
     semantic_model: &SemanticModel,
     use_range_set: &mut HashSet<TextRange>,
@@ -43,6 +242,7 @@ fn check_name_expr(
     semantic_model: &SemanticModel,
     use_range_set: &mut HashSet<TextRange>,
     name_expr: LuaNameExpr,
+    sandbox: XmakeSandbox,
 ) -> Option<()> {
     let name_range = name_expr.get_range();
     if use_range_set.contains(&name_range) {
@@ -54,11 +254,26 @@ fn check_name_expr(
         return Some(());
     }
 
-    if semantic_model
-        .get_db()
-        .get_global_index()
-        .is_exist_global_decl(&name_text)
-    {
+    let is_global = match sandbox {
+        XmakeSandbox::Description => is_description_global(semantic_model, &name_text),
+        XmakeSandbox::Script => semantic_model
+            .get_db()
+            .get_global_index()
+            .is_exist_global_decl(&name_text),
+    };
+    if is_global {
+        let description_members = match name_text.as_str() {
+            "os" => Some(DESCRIPTION_OS_MEMBERS),
+            "io" => Some(DESCRIPTION_IO_MEMBERS),
+            "path" => Some(DESCRIPTION_PATH_MEMBERS),
+            "table" => Some(DESCRIPTION_TABLE_MEMBERS),
+            _ => None,
+        };
+        if sandbox == XmakeSandbox::Description
+            && let Some(members) = description_members
+        {
+            check_description_member(context, name_expr, members);
+        }
         return Some(());
     }
 
@@ -85,16 +300,60 @@ fn check_name_expr(
         }
     }
 
+    let is_script_global = sandbox == XmakeSandbox::Description
+        && semantic_model
+            .get_db()
+            .get_global_index()
+            .is_exist_global_decl(&name_text);
+    let message = if is_script_global {
+        t!(
+            "%{name} is only available in script scope, not in the description scope",
+            name = name_text
+        )
+    } else {
+        t!("undefined global variable: %{name}", name = name_text)
+    };
     context.add_diagnostic(
         DiagnosticCode::UndefinedGlobal,
         name_range,
-        t!("undefined global variable: %{name}", name = name_text).to_string(),
+        message.to_string(),
         None,
     );
 
     Some(())
 }
 
+/// `os.rm`, `io.writefile` and other side effects or extensions are only available to scripts
+fn check_description_member(
+    context: &mut DiagnosticContext,
+    name_expr: LuaNameExpr,
+    members: &[&str],
+) -> Option<()> {
+    let module_name = name_expr.get_name_text()?;
+    let index_expr = name_expr.get_parent::<LuaIndexExpr>()?;
+    if index_expr.get_prefix_expr()? != LuaExpr::NameExpr(name_expr) {
+        return None;
+    }
+    let member_name = index_expr.get_index_name_token()?;
+    let member_name = member_name.text();
+    if members.contains(&member_name) {
+        return None;
+    }
+
+    let name = format!("{}.{}", module_name, member_name);
+    context.add_diagnostic(
+        DiagnosticCode::UndefinedGlobal,
+        index_expr.get_range(),
+        t!(
+            "%{name} is only available in script scope, not in the description scope",
+            name = name
+        )
+        .to_string(),
+        None,
+    );
+    Some(())
+}
+
 fn check_self_name(semantic_model: &SemanticModel, name_expr: LuaNameExpr) -> Option<()> {
     let closure_expr = name_expr.ancestors::<LuaClosureExpr>();
     for closure_expr in closure_expr {
@@ -0,0 +1,53 @@
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaLiteralExpr, LuaLiteralToken, LuaStringToken};
+
+use crate::{
+    DiagnosticCode, SemanticModel, XmakeToolchainArg, find_toolchains, get_toolchain_arg,
+    has_builtin_toolchains,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UndefinedToolchainChecker;
+
+impl Checker for UndefinedToolchainChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UndefinedToolchain];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        // without the toolchains of xmake every name would be unknown
+        if !has_builtin_toolchains(semantic_model.get_db()) {
+            return;
+        }
+
+        let root = semantic_model.get_root().clone();
+        for literal_expr in root.descendants::<LuaLiteralExpr>() {
+            if let Some(LuaLiteralToken::String(string_token)) = literal_expr.get_literal() {
+                check_string_arg(context, semantic_model, string_token);
+            }
+        }
+    }
+}
+
+fn check_string_arg(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    string_token: LuaStringToken,
+) -> Option<()> {
+    // `set_toolset` also accepts tools that are not toolchains, like `g++`, and a pattern like
+    // `clang.*` may match toolchains without naming one
+    if get_toolchain_arg(&string_token)? != XmakeToolchainArg::Toolchain {
+        return None;
+    }
+
+    let name = string_token.get_value();
+    if !find_toolchains(semantic_model.get_db(), &name)?.is_empty() {
+        return None;
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::UndefinedToolchain,
+        string_token.get_range(),
+        t!("Undefined toolchain `%{name}`", name = name).to_string(),
+        None,
+    );
+    Some(())
+}
@@ -0,0 +1,56 @@
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaCallExpr};
+
+use crate::{
+    DiagnosticCode, SemanticModel, find_running_task, get_option_get_key, is_common_task_option,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UnknownTaskOptionChecker;
+
+impl Checker for UnknownTaskOptionChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnknownTaskOption];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_call_expr(context, semantic_model, call_expr);
+        }
+    }
+}
+
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let key = get_option_get_key(&call_expr)?;
+    let task = find_running_task(
+        semantic_model.get_db(),
+        semantic_model.get_file_id(),
+        call_expr.syntax(),
+    )?;
+    let options = &task.properties.menu_options;
+    // without a menu there is nothing to check against
+    if options.is_empty() {
+        return None;
+    }
+
+    let name = key.get_value();
+    if is_common_task_option(&name) || options.iter().any(|option| option.name.value == name) {
+        return None;
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::UnknownTaskOption,
+        key.get_range(),
+        t!(
+            "Unknown option `%{name}` of task `%{task}`",
+            name = name,
+            task = task.name
+        )
+        .to_string(),
+        None,
+    );
+    Some(())
+}
@@ -103,6 +103,15 @@ pub enum DiagnosticCode {
     EnumValueMismatch,
     /// preferred-local-alias
     PreferredLocalAlias,
+    /// xmake api is newer than the installed xmake or `set_xmakever`, only the apis of the std
+    /// tagged with `---@since` are checked, untagged ones are assumed to be in every version
+    IncompatibleXmakeVersion,
+    /// toolchain name that is neither built into xmake nor declared with `toolchain`
+    UndefinedToolchain,
+    /// `option.get` key that is not in the menu of the running task
+    UnknownTaskOption,
+    /// field of `target(name, {...})` that is not an api of the target scope
+    UndefinedTargetAttr,
 
     #[serde(other)]
     None,
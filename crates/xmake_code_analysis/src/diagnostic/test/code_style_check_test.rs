@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::{NumberOrString, Position, Range};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_formatted_code() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::CodeStyleCheck);
+
+        assert!(ws.check_code_for(DiagnosticCode::CodeStyleCheck, "local a = 1\nprint(a)\n",));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::CodeStyleCheck,
+            "local a   =   1\nprint(a)\n",
+        ));
+    }
+
+    #[test]
+    fn test_expected_text() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::CodeStyleCheck);
+
+        let file_id = ws.def("local a = 1\nlocal b   =   2\nprint(a, b)\nlocal c =3\n");
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::CodeStyleCheck.get_name().to_string(),
+        ));
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == code)
+            .collect::<Vec<_>>();
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0].range,
+            Range::new(Position::new(1, 0), Position::new(2, 0))
+        );
+        assert_eq!(
+            diagnostics[0].data,
+            Some(serde_json::Value::String("local b = 2\n".to_string()))
+        );
+        assert_eq!(
+            diagnostics[1].range,
+            Range::new(Position::new(3, 0), Position::new(4, 0))
+        );
+        assert_eq!(
+            diagnostics[1].data,
+            Some(serde_json::Value::String("local c = 3\n".to_string()))
+        );
+    }
+}
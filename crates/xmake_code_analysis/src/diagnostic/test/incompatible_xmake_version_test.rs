@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod test {
+    use emmylua_parser::LuaVersionNumber;
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, LuaIndex, VirtualWorkspace};
+
+    const META: &str = r#"
+        ---@meta
+
+        ---@since 2.8.2
+        ---@param type string
+        function set_exceptions(type) end
+
+        ---@param name string
+        function set_kind(name) end
+    "#;
+
+    #[test]
+    fn test_required_version() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("meta.lua", META);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::IncompatibleXmakeVersion,
+            r#"
+            set_xmakever("2.8.0")
+            set_exceptions("cxx")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::IncompatibleXmakeVersion,
+            r#"
+            set_xmakever("2.9.0")
+            set_exceptions("cxx")
+            set_kind("binary")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_required_version_from_includer() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("meta.lua", META);
+        let root = ws.def_file("xmake.lua", r#"set_xmakever("2.7.0")"#);
+        let sub = ws.def_file("sub/xmake.lua", r#"set_exceptions("cxx")"#);
+        ws.get_db_mut()
+            .get_xmake_index_mut()
+            .add_includes(root, sub);
+
+        assert_eq!(
+            ws.get_db_mut().get_xmake_index().get_required_version(sub),
+            Some(LuaVersionNumber::new(2, 7, 0))
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(sub, CancellationToken::new())
+            .unwrap_or_default();
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::IncompatibleXmakeVersion
+                .get_name()
+                .to_string(),
+        ));
+        assert!(diagnostics.iter().any(|it| it.code == code));
+    }
+
+    #[test]
+    fn test_required_version_through_include_chain() {
+        let mut ws = VirtualWorkspace::new();
+        let root = ws.def_file("xmake.lua", r#"set_xmakever("2.7.0")"#);
+        let mid = ws.def_file("mid/xmake.lua", "");
+        let sub = ws.def_file("mid/sub/xmake.lua", "");
+        let index = ws.get_db_mut().get_xmake_index_mut();
+        index.add_includes(root, mid);
+        index.add_includes(mid, sub);
+        assert_eq!(
+            index.get_required_version(sub),
+            Some(LuaVersionNumber::new(2, 7, 0))
+        );
+
+        // the root no longer includes mid once it is removed
+        index.remove(root);
+        assert_eq!(index.get_required_version(sub), None);
+    }
+
+    #[test]
+    fn test_installed_version() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("meta.lua", META);
+        ws.get_db_mut()
+            .get_xmake_index_mut()
+            .set_xmake_version(LuaVersionNumber::new(2, 8, 1));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::IncompatibleXmakeVersion,
+            r#"
+            set_exceptions("cxx")
+            "#
+        ));
+
+        ws.get_db_mut()
+            .get_xmake_index_mut()
+            .set_xmake_version(LuaVersionNumber::new(3, 0, 0));
+        assert!(ws.check_code_for(
+            DiagnosticCode::IncompatibleXmakeVersion,
+            r#"
+            set_exceptions("cxx")
+            "#
+        ));
+    }
+}
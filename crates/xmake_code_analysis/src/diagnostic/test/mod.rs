@@ -4,12 +4,14 @@ mod await_in_sync_test;
 mod cast_type_mismatch_test;
 mod check_return_count_test;
 mod code_style;
+mod code_style_check_test;
 mod disable_line_test;
 mod duplicate_field_test;
 mod duplicate_index_test;
 mod duplicate_require_test;
 mod enum_value_mismatch_test;
 mod generic_constraint_mismatch_test;
+mod incompatible_xmake_version_test;
 mod incomplete_signature_doc_test;
 mod inject_field_test;
 mod missing_fields_test;
@@ -21,11 +23,14 @@ mod redundant_parameter_test;
 mod require_module_visibility_test;
 mod return_type_mismatch_test;
 mod syntax_error_test;
+mod target_attr_test;
 mod unbalanced_assignments_test;
 mod undefined_doc_param_test;
 mod undefined_field_test;
 mod undefined_global_test;
+mod undefined_toolchain_test;
 mod unknown_doc_tag;
+mod unknown_task_option_test;
 mod unnecessary_assert_test;
 mod unnecessary_if_test;
 mod unused_test;
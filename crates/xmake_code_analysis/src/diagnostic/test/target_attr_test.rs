@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{
+        DiagnosticCode, LuaMemberOwner, LuaTypeDeclId, VirtualWorkspace, get_target_attr_apis,
+    };
+
+    const META: &str = r#"
+        ---@meta
+
+        ---@class TargetAttr
+
+        ---@param target string
+        ---@param attr TargetAttr
+        function target(target, attr) end
+
+        ---@scope target
+        function set_kind(kind) end
+
+        ---@scope target
+        function add_files(file, ...) end
+
+        ---@scope target
+        function add_deps(name, ...) end
+
+        ---@scope target
+        function on_build(func) end
+
+        ---@scope option
+        function set_showmenu(show) end
+
+        function add_requires(name, ...) end
+    "#;
+
+    #[test]
+    fn test_target_attr() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file("meta.lua", META);
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {kind = "binary", files = {"src/*.c"}, deps = "core"})
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {kind = "binary", on_build = function (target) end})
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {kind = "binary", filess = "src/*.c"})
+            "#
+        ));
+        // apis of other scopes are not fields of a target
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {showmenu = true})
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {requires = "zlib"})
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {build = function (target) end})
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {["dep"] = "core"})
+            "#
+        ));
+        // only the attributes of the target are checked
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {kind = "binary", files = {name = "src/*.c"}})
+            local t = {filess = "src/*.c"}
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_target_attr_std() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {
+                kind = "binary",
+                policy = {["build.warning"] = true},
+                values = {"wdk.env", "kmdf"},
+                vectorexts = "avx2",
+                runargs = "--help",
+                rundir = "$(projectdir)",
+                runenvs = {"PATH", "bin"},
+                cuflags = "-G",
+                rcflags = "-v",
+                forceincludes = "config.h",
+                extrafiles = "README.md",
+                filegroups = "src",
+                license = "MIT",
+                version = "1.0.0",
+                on_link = function (target) end,
+                on_package = function (target) end,
+                on_uninstall = function (target) end,
+                before_link = function (target) end,
+                after_run = function (target) end,
+            })
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedTargetAttr,
+            r#"
+            target("app", {kindd = "binary"})
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_target_attr_class_fields() {
+        let ws = VirtualWorkspace::new_with_init_std_lib();
+        let db = ws.analysis.compilation.get_db();
+        let owner = LuaMemberOwner::Type(LuaTypeDeclId::new("TargetAttr"));
+        let fields = db
+            .get_member_index()
+            .get_members(&owner)
+            .unwrap()
+            .iter()
+            .map(|member| member.get_key().to_path())
+            .collect::<HashSet<_>>();
+        let apis = get_target_attr_apis(db).into_keys().collect::<HashSet<_>>();
+        assert_eq!(fields, apis);
+    }
+
+    #[test]
+    fn test_target_attr_type() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedField,
+            r#"
+            ---@type TargetAttr
+            local attr = {kind = "binary"}
+            print(attr.kind, attr.version, attr.on_build)
+            "#
+        ));
+    }
+}
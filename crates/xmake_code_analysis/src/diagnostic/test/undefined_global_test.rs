@@ -20,4 +20,176 @@ mod test {
             "#
         ));
     }
+
+    #[test]
+    fn test_description_scope() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            target("app")
+                set_kind("binary")
+                add_defines("DEBUG")
+                if is_plat("windows") then
+                    add_files(path.join(os.scriptdir(), "*.cpp"))
+                end
+            "#
+        ));
+        // misspelled API
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            target("app")
+                add_define("DEBUG")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_description_builtins() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            set_version(io.readfile("version.txt"))
+            target(format("%s_%s", "app", "cli"))
+                cprint("${green}configured")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_script_only_globals_in_description_scope() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"import("core.base.json")"#
+        ));
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"io.writefile("version.txt", "1.0")"#
+        ));
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"inherit("core.tools.gcc")"#
+        ));
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"os.rm("build")"#
+        ));
+    }
+
+    #[test]
+    fn test_description_path_members() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            add_includedirs(path.join(os.scriptdir(), "include"))
+            set_configvar("NAME", path.basename(path.absolute("src/main.cpp")))
+            "#
+        ));
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"set_configvar("SEP", path.islastsep("src/"))"#
+        ));
+    }
+
+    #[test]
+    fn test_description_table_members() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            local defines = table.join({"DEBUG"}, {"TRACE"})
+            table.insert(defines, "LOG")
+            add_defines(table.unique(defines))
+            "#
+        ));
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            local defines = {"DEBUG"}
+            table.clear(defines)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_included_description_file() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def_file("xmake.lua", r#"includes("options.lua")"#);
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "options.lua",
+            r#"os.rm("build")"#
+        ));
+    }
+
+    #[test]
+    fn test_script_scope() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            target("app")
+                on_load(function (target)
+                    import("core.base.json")
+                    local content = io.readfile("version.txt")
+                    os.rm("build")
+                    json.decode(content)
+                end)
+            "#
+        ));
+        // modules and scripts outside of the description
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "modules/build.lua",
+            r#"
+            import("core.base.option")
+            os.rm(option.get("dir"))
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_script_scope_builtins() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "xmake.lua",
+            r#"
+            target("app")
+                on_load(function (t) print(format("%s", "x")) end)
+                on_build(function (t)
+                    cprintf("${bright}%s", vformat("$(buildir)"))
+                    vprint(val("host"), bit.band(1, 3))
+                    if is_host("windows") then
+                        print(winos.version())
+                    elseif is_host("macosx") then
+                        print(macos.version())
+                    else
+                        print(linuxos.name())
+                    end
+                end)
+            "#
+        ));
+        assert!(ws.check_file_for(
+            DiagnosticCode::UndefinedGlobal,
+            "modules/gcc_ex.lua",
+            r#"inherit("core.tools.gcc")"#
+        ));
+    }
 }
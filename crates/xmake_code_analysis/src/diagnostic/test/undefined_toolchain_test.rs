@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace, WorkspaceId};
+
+    fn def_builtin_toolchains(ws: &mut VirtualWorkspace) {
+        let root = ws.virtual_url_generator.base.join("toolchains");
+        ws.analysis
+            .add_custom_workspace(WorkspaceId::BUILTIN_TOOLCHAIN, root);
+        ws.def_files(vec![
+            (
+                "toolchains/gcc/xmake.lua",
+                r#"
+                toolchain("gcc")
+                    set_description("GNU Compiler Collection")
+                toolchain_end()
+                "#,
+            ),
+            (
+                "toolchains/clang/xmake.lua",
+                r#"
+                toolchain("clang")
+                toolchain_end()
+                "#,
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_undefined_toolchain() {
+        let mut ws = VirtualWorkspace::new();
+        def_builtin_toolchains(&mut ws);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedToolchain,
+            r#"
+            target("app")
+                set_toolchains("gcc", "clangd")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedToolchain,
+            r#"
+            target("app", {toolchains = {"gcc", "clangd"}})
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedToolchain,
+            r#"
+            if is_config("toolchain", "msvc") then
+            end
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedToolchain,
+            r#"
+            target("app")
+                set_toolchains("gcc", "clang-18", "clang@llvm", "@muslcc")
+                add_toolchains("clang")
+                set_toolset("cxx", "g++")
+            if is_config("toolchain", "gcc") then
+            end
+            if is_config("toolchain", "clang.*", "gcc-1%d", "^mingw$") then
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_user_toolchain() {
+        let mut ws = VirtualWorkspace::new();
+        def_builtin_toolchains(&mut ws);
+        ws.def_file(
+            "toolchains.lua",
+            r#"
+            toolchain("mytool")
+                set_kind("standalone")
+            toolchain_end()
+            "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedToolchain,
+            r#"
+            target("app")
+                set_toolchains("mytool")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_without_builtin_toolchains() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedToolchain,
+            r#"
+            target("app")
+                set_toolchains("anything")
+            "#
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    const MENU: &str = r#"
+        set_menu {
+            usage = "xmake hello [options]",
+            options = {
+                {'k', "key", "kv", nil, "The key"},
+                {},
+                {nil, "files", "vs", nil, "The files"}
+            }
+        }
+    "#;
+
+    #[test]
+    fn test_inline_on_run() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnknownTaskOption,
+            &format!(
+                r#"
+                task("hello")
+                    {MENU}
+                    on_run(function ()
+                        print(option.get("key"), option.get("files"), option.get("verbose"))
+                    end)
+                "#
+            )
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnknownTaskOption,
+            &format!(
+                r#"
+                task("hello")
+                    {MENU}
+                    on_run(function ()
+                        print(option.get("kye"))
+                    end)
+                "#
+            )
+        ));
+        // targets have no menu
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnknownTaskOption,
+            r#"
+            target("app")
+                on_run(function ()
+                    print(option.get("kye"))
+                end)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_run_script() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "plugins/hello/xmake.lua",
+            &format!(
+                r#"
+                task("hello")
+                    {MENU}
+                    on_run("main")
+                "#
+            ),
+        );
+
+        assert!(!ws.check_file_for(
+            DiagnosticCode::UnknownTaskOption,
+            "plugins/hello/main.lua",
+            r#"
+            import("core.base.option")
+            function main()
+                print(option.get("key"), option.get("kye"))
+            end
+            "#
+        ));
+        assert!(ws.check_file_for(
+            DiagnosticCode::UnknownTaskOption,
+            "plugins/other/main.lua",
+            r#"
+            import("core.base.option")
+            function main()
+                print(option.get("kye"))
+            end
+            "#
+        ));
+    }
+}
@@ -28,7 +28,11 @@ use lsp_types::Uri;
 pub use profile::Profile;
 use resources::load_resource_std;
 pub use semantic::*;
-use std::{collections::HashSet, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::Arc,
+};
 pub use test_lib::VirtualWorkspace;
 use tokio_util::sync::CancellationToken;
 pub use vfs::*;
@@ -130,10 +134,14 @@ impl XmakeAnalysis {
             .get_vfs_mut()
             .set_file_content(uri, text);
 
+        let old_interface = self.get_include_interface(file_id);
         self.compilation.remove_index(vec![file_id]);
         if !is_removed {
             self.compilation.update_index(vec![file_id]);
         }
+        if old_interface.is_none() || old_interface != self.get_include_interface(file_id) {
+            self.update_includers(&[file_id]);
+        }
 
         Some(file_id)
     }
@@ -146,6 +154,7 @@ impl XmakeAnalysis {
     pub fn update_files_by_uri(&mut self, files: Vec<(Uri, Option<String>)>) -> Vec<FileId> {
         let mut removed_files = HashSet::new();
         let mut updated_files = HashSet::new();
+        let mut old_interfaces = HashMap::new();
         {
             let _p = Profile::new("update files");
             for (uri, text) in files {
@@ -155,6 +164,7 @@ impl XmakeAnalysis {
                     .get_db_mut()
                     .get_vfs_mut()
                     .set_file_content(&uri, text);
+                old_interfaces.insert(file_id, self.get_include_interface(file_id));
                 removed_files.insert(file_id);
                 if is_new_text {
                     updated_files.insert(file_id);
@@ -165,6 +175,15 @@ impl XmakeAnalysis {
             .remove_index(removed_files.into_iter().collect());
         let updated_files: Vec<FileId> = updated_files.into_iter().collect();
         self.compilation.update_index(updated_files.clone());
+
+        let changed_files = old_interfaces
+            .into_iter()
+            .filter(|(file_id, old_interface)| {
+                old_interface.is_none() || *old_interface != self.get_include_interface(*file_id)
+            })
+            .map(|(file_id, _)| file_id)
+            .collect::<Vec<_>>();
+        self.update_includers(&changed_files);
         updated_files
     }
 
@@ -199,8 +218,14 @@ impl XmakeAnalysis {
     }
 
     pub fn remove_file_by_uri(&mut self, uri: &Uri) -> Option<FileId> {
+        let includers = match self.get_file_id(uri) {
+            Some(file_id) => self.collect_includers(&[file_id]),
+            None => vec![],
+        };
         if let Some(file_id) = self.compilation.get_db_mut().get_vfs_mut().remove_file(uri) {
             self.compilation.remove_index(vec![file_id]);
+            self.compilation.remove_index(includers.clone());
+            self.compilation.update_index(includers);
             return Some(file_id);
         }
 
@@ -218,6 +243,79 @@ impl XmakeAnalysis {
         self.update_files_by_uri(files)
     }
 
+    /// Re-analyze the files including `file_ids`, directly or not, and the files with an
+    /// unresolved `includes` of one of them
+    fn update_includers(&mut self, file_ids: &[FileId]) {
+        let includers = self.collect_includers(file_ids);
+        if includers.is_empty() {
+            return;
+        }
+        self.compilation.remove_index(includers.clone());
+        self.compilation.update_index(includers);
+    }
+
+    /// Files including `file_ids`, directly or not, and the files with an unresolved `includes`
+    /// of one of them, without `file_ids` themselves
+    pub fn collect_includers(&self, file_ids: &[FileId]) -> Vec<FileId> {
+        let db = self.compilation.get_db();
+        let xmake_index = db.get_xmake_index();
+        let mut stack = file_ids.to_vec();
+        for file_id in file_ids {
+            if let Some(path) = db.get_vfs().get_file_path(file_id) {
+                stack.extend(
+                    db.get_file_dependencies_index()
+                        .get_unresolved_includers(path),
+                );
+            }
+        }
+
+        let mut includers = Vec::new();
+        let mut visited = file_ids.iter().copied().collect::<HashSet<_>>();
+        while let Some(file_id) = stack.pop() {
+            if visited.insert(file_id) {
+                includers.push(file_id);
+            }
+            for includer in xmake_index.get_includers(file_id).into_iter().flatten() {
+                if !visited.contains(includer) {
+                    stack.push(*includer);
+                }
+            }
+        }
+        includers.sort();
+        includers
+    }
+
+    /// What the files including `file_id` read from it: the env declarations it exports with
+    /// their types and its own `includes`. `None` when the file is not indexed
+    fn get_include_interface(&self, file_id: FileId) -> Option<Vec<String>> {
+        let db = self.compilation.get_db();
+        let decl_tree = db.get_decl_index().get_decl_tree(&file_id)?;
+        let mut interface = decl_tree
+            .get_export_env_decls()
+            .into_iter()
+            .filter_map(|decl_id| {
+                let decl = decl_tree.get_decl(&decl_id)?;
+                let typ = db
+                    .get_type_index()
+                    .get_type_cache(&decl_id.into())
+                    .map(|type_cache| {
+                        humanize_type(db, type_cache.as_type(), RenderLevel::Detailed)
+                    })
+                    .unwrap_or_default();
+                Some(format!("{}: {}", decl.get_name(), typ))
+            })
+            .collect::<Vec<_>>();
+        interface.sort();
+        if let Some(includes) = db.get_xmake_index().get_includes(file_id) {
+            interface.extend(
+                includes
+                    .iter()
+                    .map(|include| format!("includes {}", include.id)),
+            );
+        }
+        Some(interface)
+    }
+
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
         self.emmyrc = config.clone();
         self.compilation.update_config(config.clone());
@@ -146,6 +146,21 @@ impl VirtualWorkspace {
 
     pub fn check_code_for(&mut self, diagnostic_code: DiagnosticCode, block_str: &str) -> bool {
         let file_id = self.def(block_str);
+        self.check_file_id_for(diagnostic_code, file_id)
+    }
+
+    /// Like `check_code_for`, for checks that depend on the file name, e.g. `xmake.lua`
+    pub fn check_file_for(
+        &mut self,
+        diagnostic_code: DiagnosticCode,
+        file_name: &str,
+        block_str: &str,
+    ) -> bool {
+        let file_id = self.def_file(file_name, block_str);
+        self.check_file_id_for(diagnostic_code, file_id)
+    }
+
+    fn check_file_id_for(&mut self, diagnostic_code: DiagnosticCode, file_id: FileId) -> bool {
         let result = self
             .analysis
             .diagnose_file(file_id, CancellationToken::new());
@@ -0,0 +1,15 @@
+use clap::Parser;
+use std::process::ExitCode;
+use xmake_fmt::CmdArgs;
+
+fn main() -> ExitCode {
+    let cmd_args = CmdArgs::parse();
+    match xmake_fmt::run_fmt(cmd_args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("xmake_fmt: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
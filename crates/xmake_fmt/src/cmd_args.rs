@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "cli")]
+use clap::Parser;
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "cli", derive(Parser))]
+#[cfg_attr(feature = "cli", command(version, about = "Format xmake.lua files"))]
+pub struct CmdArgs {
+    /// Files or directories to format. Defaults to the current directory.
+    #[cfg_attr(feature = "cli", arg(value_name = "PATH"))]
+    pub paths: Vec<PathBuf>,
+
+    /// Don't write files, exit with a non-zero code if any file is not formatted.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub check: bool,
+
+    /// Don't write files, print a unified diff for every file that is not formatted.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub diff: bool,
+
+    /// Read the source from stdin and write the result to stdout.
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub stdin: bool,
+
+    /// Path to the config file providing `workspace.ignoreGlobs` and `workspace.ignoreDir`.
+    /// Defaults to `.emmyrc.json` in the current directory.
+    #[cfg_attr(feature = "cli", arg(long, value_name = "FILE"))]
+    pub config: Option<PathBuf>,
+}
+
+impl CmdArgs {
+    /// Whether files are left untouched and only reported.
+    pub fn is_dry_run(&self) -> bool {
+        self.check || self.diff
+    }
+}
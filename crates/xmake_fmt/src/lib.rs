@@ -0,0 +1,183 @@
+mod cmd_args;
+mod test;
+
+pub use cmd_args::CmdArgs;
+
+use std::{
+    error::Error,
+    fs,
+    io::{self, Read, Write},
+    path::PathBuf,
+};
+
+use emmylua_parser::{LuaAst, LuaParser, ParserConfig};
+use similar::TextDiff;
+use xmake_code_analysis::{
+    Emmyrc, LuaFileInfo, load_configs, load_workspace_files, read_file_with_encoding,
+};
+use xmake_formatter::reformat_node;
+
+const STDIN_NAME: &str = "<stdin>";
+
+/// Result of formatting a single source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatResult {
+    /// The source is already formatted.
+    Unchanged,
+    /// The source differs from the formatted text.
+    Changed(String),
+    /// The source has syntax errors and was not formatted.
+    SyntaxError,
+}
+
+pub fn format_text(code: &str) -> FormatResult {
+    let tree = LuaParser::parse(code, ParserConfig::default());
+    if tree.has_syntax_errors() {
+        return FormatResult::SyntaxError;
+    }
+
+    let formatted = reformat_node(&LuaAst::LuaChunk(tree.get_chunk_node()));
+    if formatted == code {
+        FormatResult::Unchanged
+    } else {
+        FormatResult::Changed(formatted)
+    }
+}
+
+pub fn unified_diff(name: &str, origin: &str, formatted: &str) -> String {
+    TextDiff::from_lines(origin, formatted)
+        .unified_diff()
+        .context_radius(3)
+        .header(name, name)
+        .to_string()
+}
+
+/// Runs the formatter, returns `Ok(true)` when every input is formatted (or was written back)
+/// and no file failed to parse.
+pub fn run_fmt(cmd_args: CmdArgs) -> Result<bool, Box<dyn Error>> {
+    if cmd_args.stdin {
+        return run_stdin(&cmd_args);
+    }
+
+    let emmyrc = load_emmyrc(&cmd_args);
+    let mut success = true;
+    let files = collect_files(&cmd_args.paths, &emmyrc, &mut success);
+    let mut stdout = io::stdout().lock();
+    for file in files {
+        match format_text(&file.content) {
+            FormatResult::Unchanged => {}
+            FormatResult::Changed(formatted) => {
+                if cmd_args.diff {
+                    write!(
+                        stdout,
+                        "{}",
+                        unified_diff(&file.path, &file.content, &formatted)
+                    )?;
+                } else if cmd_args.check {
+                    eprintln!("Unformatted: {}", file.path);
+                } else {
+                    fs::write(&file.path, formatted)?;
+                    eprintln!("Formatted: {}", file.path);
+                }
+
+                if cmd_args.is_dry_run() {
+                    success = false;
+                }
+            }
+            FormatResult::SyntaxError => {
+                eprintln!("Skipped (syntax error): {}", file.path);
+                success = false;
+            }
+        }
+    }
+
+    Ok(success)
+}
+
+fn run_stdin(cmd_args: &CmdArgs) -> Result<bool, Box<dyn Error>> {
+    let mut code = String::new();
+    io::stdin().read_to_string(&mut code)?;
+
+    let mut stdout = io::stdout().lock();
+    match format_text(&code) {
+        FormatResult::Unchanged => {
+            if !cmd_args.is_dry_run() {
+                write!(stdout, "{}", code)?;
+            }
+            Ok(true)
+        }
+        FormatResult::Changed(formatted) => {
+            if cmd_args.diff {
+                write!(stdout, "{}", unified_diff(STDIN_NAME, &code, &formatted))?;
+            } else if !cmd_args.check {
+                write!(stdout, "{}", formatted)?;
+                return Ok(true);
+            }
+            Ok(false)
+        }
+        FormatResult::SyntaxError => {
+            eprintln!("Skipped (syntax error): {}", STDIN_NAME);
+            if !cmd_args.is_dry_run() {
+                write!(stdout, "{}", code)?;
+            }
+            Ok(false)
+        }
+    }
+}
+
+fn load_emmyrc(cmd_args: &CmdArgs) -> Emmyrc {
+    let config_path = match &cmd_args.config {
+        Some(path) => path.clone(),
+        None => {
+            let default_path = PathBuf::from(".emmyrc.json");
+            if !default_path.exists() {
+                return Emmyrc::default();
+            }
+            default_path
+        }
+    };
+
+    load_configs(vec![config_path], None)
+}
+
+fn collect_files(paths: &[PathBuf], emmyrc: &Emmyrc, success: &mut bool) -> Vec<LuaFileInfo> {
+    let include = vec!["**/*.lua".to_string()];
+    let exclude = emmyrc.workspace.ignore_globs.clone();
+    let exclude_dir = emmyrc
+        .workspace
+        .ignore_dir
+        .iter()
+        .map(PathBuf::from)
+        .collect::<Vec<_>>();
+    let encoding = emmyrc.workspace.encoding.as_str();
+
+    let default_paths = [PathBuf::from(".")];
+    let paths = if paths.is_empty() {
+        &default_paths[..]
+    } else {
+        paths
+    };
+
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            match load_workspace_files(path, &include, &exclude, &exclude_dir, Some(encoding)) {
+                Ok(loaded) => files.extend(loaded),
+                Err(e) => {
+                    eprintln!("Failed to read directory {}: {}", path.display(), e);
+                    *success = false;
+                }
+            }
+        } else if let Some(content) = read_file_with_encoding(path, encoding) {
+            files.push(LuaFileInfo {
+                path: path.to_string_lossy().to_string(),
+                content,
+            });
+        } else {
+            eprintln!("Failed to read file: {}", path.display());
+            *success = false;
+        }
+    }
+
+    files
+}
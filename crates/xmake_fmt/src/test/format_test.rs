@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod test {
+    use crate::{FormatResult, format_text, unified_diff};
+
+    #[test]
+    fn test_format_text() {
+        let code = "local a =  1\n";
+        let FormatResult::Changed(formatted) = format_text(code) else {
+            panic!("expected the code to be reformatted");
+        };
+        assert_eq!(formatted, "local a = 1\n");
+        assert_eq!(format_text(&formatted), FormatResult::Unchanged);
+        assert_eq!(format_text("target(\"a\""), FormatResult::SyntaxError);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let diff = unified_diff("xmake.lua", "local a =  1\n", "local a = 1\n");
+        assert_eq!(
+            diff,
+            "--- xmake.lua\n+++ xmake.lua\n@@ -1 +1 @@\n-local a =  1\n+local a = 1\n"
+        );
+    }
+}
@@ -5,17 +5,38 @@ use std::{
 
 use lsp_server::{Connection, Message, Notification, RequestId, Response};
 use lsp_types::{
-    ApplyWorkspaceEditParams, ApplyWorkspaceEditResponse, ConfigurationParams, MessageActionItem,
-    PublishDiagnosticsParams, RegistrationParams, ShowMessageParams, ShowMessageRequestParams,
-    UnregistrationParams,
+    ApplyWorkspaceEditParams, ApplyWorkspaceEditResponse, ConfigurationParams, LogMessageParams,
+    MessageActionItem, PublishDiagnosticsParams, RegistrationParams, ShowMessageParams,
+    ShowMessageRequestParams, UnregistrationParams,
 };
-use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use tokio::{
     select,
     sync::{Mutex, oneshot},
 };
 use tokio_util::sync::CancellationToken;
 
+/// `xmake/showInputBox`, asks the user for a line of text, like `window/showMessageRequest` asks
+/// for one of its actions. The result is `null` when the user dismisses the input
+#[derive(Debug)]
+pub enum ShowInputBoxRequest {}
+
+impl lsp_types::request::Request for ShowInputBoxRequest {
+    type Params = ShowInputBoxParams;
+    type Result = Option<String>;
+    const METHOD: &'static str = "xmake/showInputBox";
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowInputBoxParams {
+    pub prompt: String,
+    /// Hint shown while the input is empty
+    pub placeholder: Option<String>,
+    /// Initial text of the input
+    pub value: Option<String>,
+}
+
 pub struct ClientProxy {
     conn: Connection,
     id_counter: AtomicI32,
@@ -115,10 +136,20 @@ impl ClientProxy {
         );
     }
 
+    /// Asks the client to pull the diagnostics of all documents again
+    pub fn refresh_workspace_diagnostics(&self) {
+        let request_id = self.next_id();
+        self.send_request_no_wait(request_id, "workspace/diagnostic/refresh", ());
+    }
+
     pub fn show_message(&self, message: ShowMessageParams) {
         self.send_notification("window/showMessage", message);
     }
 
+    pub fn log_message(&self, message: LogMessageParams) {
+        self.send_notification("window/logMessage", message);
+    }
+
     pub async fn show_message_request(
         &self,
         params: ShowMessageRequestParams,
@@ -136,6 +167,24 @@ impl ClientProxy {
         serde_json::from_value(response.result?).ok()
     }
 
+    /// `None` when the user dismisses the input or the client doesn't handle `xmake/showInputBox`
+    pub async fn show_input_box(
+        &self,
+        params: ShowInputBoxParams,
+        cancel_token: CancellationToken,
+    ) -> Option<String> {
+        let request_id = self.next_id();
+        let response = self
+            .send_request(
+                request_id,
+                <ShowInputBoxRequest as lsp_types::request::Request>::METHOD,
+                params,
+                cancel_token,
+            )
+            .await?;
+        serde_json::from_value(response.result?).ok()?
+    }
+
     pub fn publish_diagnostics(&self, params: PublishDiagnosticsParams) {
         self.send_notification("textDocument/publishDiagnostics", params);
     }
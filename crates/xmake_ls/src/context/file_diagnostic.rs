@@ -1,18 +1,104 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{DefaultHasher, Hash, Hasher},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
 use log::{debug, info};
-use tokio::sync::{Mutex, RwLock};
+use lsp_types::{ClientCapabilities, Diagnostic, Uri};
+use tokio::sync::{Mutex, RwLock, watch};
 use tokio_util::sync::CancellationToken;
 use xmake_code_analysis::{FileId, Profile, XmakeAnalysis};
 
 use super::{ClientProxy, ProgressTask, StatusBar};
 
+/// How diagnostics reach the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticMode {
+    /// Published with `textDocument/publishDiagnostics`
+    Push,
+    /// Requested by the client with `textDocument/diagnostic` and `workspace/diagnostic`
+    Pull { refresh_support: bool },
+}
+
+impl DiagnosticMode {
+    pub fn from_client_capabilities(client_capabilities: &ClientCapabilities) -> Self {
+        let supports_pull = client_capabilities
+            .text_document
+            .as_ref()
+            .is_some_and(|text_document| text_document.diagnostic.is_some());
+        if !supports_pull {
+            return DiagnosticMode::Push;
+        }
+
+        let refresh_support = client_capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.diagnostic.as_ref())
+            .and_then(|diagnostic| diagnostic.refresh_support)
+            .unwrap_or_default();
+        DiagnosticMode::Pull { refresh_support }
+    }
+}
+
+/// Diagnostics of a file computed for a pull request, reused until the file is invalidated
+struct PullReport {
+    generation: PullGeneration,
+    result_id: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// The generation of every file and of a single file, a report computed for an older one is
+/// stale
+type PullGeneration = (u64, u64);
+
+#[derive(Default)]
+struct PullReports {
+    reports: HashMap<FileId, PullReport>,
+    /// Bumped when every report is stale, e.g. after a reindex or a config change
+    generation: u64,
+    /// Bumped when the diagnostics of a single file may have changed
+    file_generations: HashMap<FileId, u64>,
+}
+
+impl PullReports {
+    fn get_generation(&self, file_id: FileId) -> PullGeneration {
+        let file_generation = self.file_generations.get(&file_id).copied();
+        (self.generation, file_generation.unwrap_or_default())
+    }
+
+    fn invalidate_files(&mut self, file_ids: &[FileId]) {
+        for file_id in file_ids {
+            self.reports.remove(file_id);
+            *self.file_generations.entry(*file_id).or_default() += 1;
+        }
+    }
+
+    fn invalidate_all(&mut self) {
+        self.reports.clear();
+        self.generation += 1;
+    }
+}
+
 pub struct FileDiagnostic {
     analysis: Arc<RwLock<XmakeAnalysis>>,
     client: Arc<ClientProxy>,
     status_bar: Arc<StatusBar>,
     diagnostic_tokens: Arc<Mutex<HashMap<FileId, CancellationToken>>>,
     workspace_diagnostic_token: Arc<Mutex<Option<CancellationToken>>>,
+    /// Diagnostics of the last xmake build by uri string, published together with the analysis
+    /// diagnostics
+    build_diagnostics: Arc<Mutex<HashMap<String, Vec<Diagnostic>>>>,
+    mode: DiagnosticMode,
+    pull_reports: Arc<Mutex<PullReports>>,
+    /// Bumped whenever some pulled diagnostics may have changed, wakes up the pending
+    /// `workspace/diagnostic` request
+    changes: Arc<watch::Sender<u64>>,
+    /// Files removed since the last workspace pull, reported once with no diagnostics
+    removed_uris: Arc<Mutex<HashSet<Uri>>>,
+    refresh_token: Arc<Mutex<Option<CancellationToken>>>,
 }
 
 impl FileDiagnostic {
@@ -20,17 +106,33 @@ impl FileDiagnostic {
         analysis: Arc<RwLock<XmakeAnalysis>>,
         status_bar: Arc<StatusBar>,
         client: Arc<ClientProxy>,
+        mode: DiagnosticMode,
     ) -> Self {
         Self {
             analysis,
             client,
             diagnostic_tokens: Arc::new(Mutex::new(HashMap::new())),
             workspace_diagnostic_token: Arc::new(Mutex::new(None)),
+            build_diagnostics: Arc::new(Mutex::new(HashMap::new())),
             status_bar,
+            mode,
+            pull_reports: Arc::new(Mutex::new(PullReports::default())),
+            changes: Arc::new(watch::Sender::new(0)),
+            removed_uris: Arc::new(Mutex::new(HashSet::new())),
+            refresh_token: Arc::new(Mutex::new(None)),
         }
     }
 
+    pub fn is_pull_mode(&self) -> bool {
+        matches!(self.mode, DiagnosticMode::Pull { .. })
+    }
+
     pub async fn add_diagnostic_task(&self, file_id: FileId, interval: u64) {
+        if self.is_pull_mode() {
+            self.invalidate_pull_reports(&[file_id], interval).await;
+            return;
+        }
+
         let mut tokens = self.diagnostic_tokens.lock().await;
 
         if let Some(token) = tokens.get(&file_id) {
@@ -46,6 +148,7 @@ impl FileDiagnostic {
         let analysis = self.analysis.clone();
         let client = self.client.clone();
         let diagnostic_tokens = self.diagnostic_tokens.clone();
+        let build_diagnostics = self.build_diagnostics.clone();
         let file_id_clone = file_id.clone();
 
         // Spawn a new task to perform diagnostic
@@ -55,7 +158,8 @@ impl FileDiagnostic {
                     let analysis = analysis.read().await;
                     if let Some(uri) = analysis.get_uri(file_id_clone) {
                         let diagnostics = analysis.diagnose_file(file_id_clone, cancel_token);
-                        if let Some(diagnostics) = diagnostics {
+                        if let Some(mut diagnostics) = diagnostics {
+                            append_build_diagnostics(&build_diagnostics, &uri, &mut diagnostics).await;
                             let diagnostic_param = lsp_types::PublishDiagnosticsParams {
                                 uri,
                                 diagnostics,
@@ -79,6 +183,11 @@ impl FileDiagnostic {
 
     // todo add message show
     pub async fn add_files_diagnostic_task(&self, file_ids: Vec<FileId>, interval: u64) {
+        if self.is_pull_mode() {
+            self.invalidate_pull_reports(&file_ids, interval).await;
+            return;
+        }
+
         for file_id in file_ids {
             self.add_diagnostic_task(file_id, interval).await;
         }
@@ -86,6 +195,12 @@ impl FileDiagnostic {
 
     /// 清除指定文件的诊断信息
     pub async fn clear_file_diagnostics(&self, uri: lsp_types::Uri) {
+        if self.is_pull_mode() {
+            self.removed_uris.lock().await.insert(uri);
+            self.invalidate_pull_reports(&[], 0).await;
+            return;
+        }
+
         let diagnostic_param = lsp_types::PublishDiagnosticsParams {
             uri,
             diagnostics: vec![],
@@ -95,6 +210,12 @@ impl FileDiagnostic {
     }
 
     pub async fn add_workspace_diagnostic_task(&self, interval: u64, silent: bool) {
+        if self.is_pull_mode() {
+            self.pull_reports.lock().await.invalidate_all();
+            self.refresh_pull_diagnostics(interval).await;
+            return;
+        }
+
         let mut token = self.workspace_diagnostic_token.lock().await;
         if let Some(token) = token.as_ref() {
             token.cancel();
@@ -108,10 +229,11 @@ impl FileDiagnostic {
         let analysis = self.analysis.clone();
         let client_proxy = self.client.clone();
         let status_bar = self.status_bar.clone();
+        let build_diagnostics = self.build_diagnostics.clone();
         tokio::spawn(async move {
             tokio::select! {
                 _ = tokio::time::sleep(Duration::from_millis(interval)) => {
-                    workspace_diagnostic(analysis, client_proxy, status_bar, silent, cancel_token).await
+                    workspace_diagnostic(analysis, client_proxy, status_bar, build_diagnostics, silent, cancel_token).await
                 }
                 _ = cancel_token.cancelled() => {
                     log::info!("cancel workspace diagnostic");
@@ -120,6 +242,146 @@ impl FileDiagnostic {
         });
     }
 
+    /// Replace the diagnostics of the last build, files that no longer have build
+    /// diagnostics are republished without them.
+    ///
+    /// In pull mode the reports of indexed files are pulled again, the `.cpp` and `.h` files of
+    /// the build have no `FileId` and are still published since pulls never cover them.
+    pub async fn set_build_diagnostics(&self, diagnostics: HashMap<String, Vec<Diagnostic>>) {
+        let mut build_diagnostics = self.build_diagnostics.lock().await;
+        let old_build_diagnostics = std::mem::replace(&mut *build_diagnostics, diagnostics);
+        let uris = old_build_diagnostics
+            .into_keys()
+            .chain(build_diagnostics.keys().cloned())
+            .collect::<HashSet<_>>();
+        let new_build_diagnostics = build_diagnostics.clone();
+        drop(build_diagnostics);
+
+        let analysis = self.analysis.read().await;
+        let mut pulled_file_ids = Vec::new();
+        for uri_text in uris {
+            let Ok(uri) = Uri::from_str(&uri_text) else {
+                continue;
+            };
+            let file_id = analysis.get_file_id(&uri);
+            if self.is_pull_mode()
+                && let Some(file_id) = file_id
+            {
+                pulled_file_ids.push(file_id);
+                continue;
+            }
+
+            let mut diagnostics = match file_id {
+                Some(file_id) => analysis
+                    .diagnose_file(file_id, CancellationToken::new())
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            };
+            if let Some(build_diagnostics) = new_build_diagnostics.get(&uri_text) {
+                diagnostics.extend(build_diagnostics.iter().cloned());
+            }
+            self.client
+                .publish_diagnostics(lsp_types::PublishDiagnosticsParams {
+                    uri,
+                    diagnostics,
+                    version: None,
+                });
+        }
+        drop(analysis);
+
+        if self.is_pull_mode() {
+            self.invalidate_pull_reports(&pulled_file_ids, 0).await;
+        }
+    }
+
+    /// Diagnostics of the file for a pull request and their result id, which only changes
+    /// with the diagnostics
+    pub async fn pull_file_diagnostics(
+        &self,
+        file_id: FileId,
+        cancel_token: CancellationToken,
+    ) -> Option<(String, Vec<Diagnostic>)> {
+        let generation = {
+            let pull_reports = self.pull_reports.lock().await;
+            let generation = pull_reports.get_generation(file_id);
+            if let Some(report) = pull_reports.reports.get(&file_id)
+                && report.generation == generation
+            {
+                return Some((report.result_id.clone(), report.diagnostics.clone()));
+            }
+            generation
+        };
+
+        let analysis = self.analysis.read().await;
+        let uri = analysis.get_uri(file_id)?;
+        let mut diagnostics = analysis.diagnose_file(file_id, cancel_token)?;
+        drop(analysis);
+        append_build_diagnostics(&self.build_diagnostics, &uri, &mut diagnostics).await;
+
+        let mut hasher = DefaultHasher::new();
+        serde_json::to_string(&diagnostics).ok()?.hash(&mut hasher);
+        let result_id = format!("{:x}", hasher.finish());
+        // a file invalidated while it was diagnosed keeps no report
+        let mut pull_reports = self.pull_reports.lock().await;
+        if pull_reports.get_generation(file_id) == generation {
+            pull_reports.reports.insert(
+                file_id,
+                PullReport {
+                    generation,
+                    result_id: result_id.clone(),
+                    diagnostics: diagnostics.clone(),
+                },
+            );
+        }
+        drop(pull_reports);
+
+        Some((result_id, diagnostics))
+    }
+
+    /// Notified whenever the pulled diagnostics may have changed
+    pub fn subscribe_changes(&self) -> watch::Receiver<u64> {
+        self.changes.subscribe()
+    }
+
+    pub async fn take_removed_uris(&self) -> HashSet<Uri> {
+        std::mem::take(&mut *self.removed_uris.lock().await)
+    }
+
+    /// Drops the cached pull reports of `file_ids` and asks the client to pull again after
+    /// `interval`, the reports of other files are kept
+    async fn invalidate_pull_reports(&self, file_ids: &[FileId], interval: u64) {
+        self.pull_reports.lock().await.invalidate_files(file_ids);
+        self.refresh_pull_diagnostics(interval).await;
+    }
+
+    async fn refresh_pull_diagnostics(&self, interval: u64) {
+        self.changes.send_modify(|changes| *changes += 1);
+        let DiagnosticMode::Pull {
+            refresh_support: true,
+        } = self.mode
+        else {
+            return;
+        };
+
+        let mut token = self.refresh_token.lock().await;
+        if let Some(token) = token.as_ref() {
+            token.cancel();
+        }
+        let cancel_token = CancellationToken::new();
+        token.replace(cancel_token.clone());
+        drop(token);
+
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_millis(interval)) => {
+                    client.refresh_workspace_diagnostics();
+                }
+                _ = cancel_token.cancelled() => {}
+            }
+        });
+    }
+
     #[allow(unused)]
     pub async fn cancel_all(&self) {
         let mut tokens = self.diagnostic_tokens.lock().await;
@@ -130,10 +392,21 @@ impl FileDiagnostic {
     }
 }
 
+async fn append_build_diagnostics(
+    build_diagnostics: &Mutex<HashMap<String, Vec<Diagnostic>>>,
+    uri: &Uri,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let Some(build_diagnostics) = build_diagnostics.lock().await.get(uri.as_str()) {
+        diagnostics.extend(build_diagnostics.iter().cloned());
+    }
+}
+
 async fn workspace_diagnostic(
     analysis: Arc<RwLock<XmakeAnalysis>>,
     client_proxy: Arc<ClientProxy>,
     status_bar: Arc<StatusBar>,
+    build_diagnostics: Arc<Mutex<HashMap<String, Vec<Diagnostic>>>>,
     silent: bool,
     cancel_token: CancellationToken,
 ) {
@@ -158,11 +431,13 @@ async fn workspace_diagnostic(
         let token = cancel_token.clone();
         let client = client_proxy.clone();
         let tx = tx.clone();
+        let build_diagnostics = build_diagnostics.clone();
         tokio::spawn(async move {
             let analysis = analysis.read().await;
             let diagnostics = analysis.diagnose_file(file_id, token);
-            if let Some(diagnostics) = diagnostics {
+            if let Some(mut diagnostics) = diagnostics {
                 let uri = analysis.get_uri(file_id).unwrap();
+                append_build_diagnostics(&build_diagnostics, &uri, &mut diagnostics).await;
                 let diagnostic_param = lsp_types::PublishDiagnosticsParams {
                     uri,
                     diagnostics,
@@ -5,9 +5,9 @@ mod snapshot;
 mod status_bar;
 mod workspace_manager;
 
-pub use client::ClientProxy;
+pub use client::{ClientProxy, ShowInputBoxParams};
 pub use client_id::{ClientId, get_client_id};
-pub use file_diagnostic::FileDiagnostic;
+pub use file_diagnostic::{DiagnosticMode, FileDiagnostic};
 use lsp_server::{Connection, ErrorCode, Message, RequestId, Response};
 use lsp_types::ClientCapabilities;
 pub use snapshot::ServerContextSnapshot;
@@ -43,16 +43,17 @@ impl ServerContext {
             analysis.clone(),
             status_bar.clone(),
             client.clone(),
+            DiagnosticMode::from_client_capabilities(&client_capabilities),
         ));
+        let xmake = Arc::new(XmakeWrapper::new());
         let workspace_manager = Arc::new(RwLock::new(WorkspaceManager::new(
             analysis.clone(),
             client.clone(),
             status_bar.clone(),
             file_diagnostic.clone(),
+            xmake.clone(),
         )));
 
-        let xmake = Arc::new(XmakeWrapper::new());
-
         ServerContext {
             conn,
             cancellations: Arc::new(Mutex::new(HashMap::new())),
@@ -1,16 +1,27 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+};
 
 use lsp_types::{
     NumberOrString, ProgressParams, ProgressParamsValue, WorkDoneProgress, WorkDoneProgressBegin,
     WorkDoneProgressCreateParams, WorkDoneProgressEnd, WorkDoneProgressReport,
 };
 
+use tokio_util::sync::CancellationToken;
+
 use crate::util::time_cancel_token;
 
 use super::ClientProxy;
 
 pub struct StatusBar {
     client: Arc<ClientProxy>,
+    /// Cancellation of the running cancellable tasks by progress token
+    cancellations: Mutex<HashMap<NumberOrString, CancellationToken>>,
+    next_run_id: AtomicU32,
 }
 
 #[allow(unused)]
@@ -20,6 +31,8 @@ pub enum ProgressTask {
     DiagnoseWorkspace = 1,
     RefreshIndex = 2,
     XmakeLoad = 3,
+    XmakeBuild = 4,
+    XmakeTask = 5,
 }
 
 impl ProgressTask {
@@ -33,16 +46,67 @@ impl ProgressTask {
             ProgressTask::DiagnoseWorkspace => "Diagnose workspace",
             ProgressTask::RefreshIndex => "Refresh index",
             ProgressTask::XmakeLoad => "Load xmake",
+            ProgressTask::XmakeBuild => "Xmake build",
+            ProgressTask::XmakeTask => "Xmake task",
         }
     }
+
+    fn get_token(&self) -> NumberOrString {
+        NumberOrString::Number(self.as_i32())
+    }
+}
+
+/// One run of a cancellable task, several runs of the same task can be in progress at once so
+/// each has its own progress token
+#[derive(Debug, Clone)]
+pub struct ProgressRun {
+    token: NumberOrString,
+    cancel_token: CancellationToken,
+}
+
+impl ProgressRun {
+    /// Cancelled when the user cancels the run from the client
+    pub fn get_cancel_token(&self) -> CancellationToken {
+        self.cancel_token.clone()
+    }
 }
 
 impl StatusBar {
     pub fn new(client: Arc<ClientProxy>) -> Self {
-        Self { client }
+        Self {
+            client,
+            cancellations: Mutex::new(HashMap::new()),
+            next_run_id: AtomicU32::new(0),
+        }
     }
 
     pub async fn create_progress_task(&self, task: ProgressTask) {
+        self.begin_progress(task.get_token(), task, false).await;
+    }
+
+    /// Create a run of a task the user can cancel from the client
+    pub async fn create_cancellable_progress_task(&self, task: ProgressTask) -> ProgressRun {
+        let run_id = self.next_run_id.fetch_add(1, Ordering::Relaxed);
+        let run = ProgressRun {
+            token: NumberOrString::String(format!("{}/{}", task.as_i32(), run_id)),
+            cancel_token: CancellationToken::new(),
+        };
+        self.cancellations
+            .lock()
+            .unwrap()
+            .insert(run.token.clone(), run.cancel_token.clone());
+        self.begin_progress(run.token.clone(), task, true).await;
+        run
+    }
+
+    /// Called when the client sends `window/workDoneProgress/cancel`
+    pub fn cancel_progress_task(&self, token: &NumberOrString) {
+        if let Some(cancel_token) = self.cancellations.lock().unwrap().remove(token) {
+            cancel_token.cancel();
+        }
+    }
+
+    async fn begin_progress(&self, token: NumberOrString, task: ProgressTask, cancellable: bool) {
         let request_id = self.client.next_id();
         let cancel_token = time_cancel_token(std::time::Duration::from_secs(5));
         let _ = self
@@ -51,7 +115,7 @@ impl StatusBar {
                 request_id,
                 "window/workDoneProgress/create",
                 WorkDoneProgressCreateParams {
-                    token: NumberOrString::Number(task.as_i32()),
+                    token: token.clone(),
                 },
                 cancel_token,
             )
@@ -59,11 +123,11 @@ impl StatusBar {
         self.client.send_notification(
             "$/progress",
             ProgressParams {
-                token: NumberOrString::Number(task as i32),
+                token,
                 value: ProgressParamsValue::WorkDone(WorkDoneProgress::Begin(
                     WorkDoneProgressBegin {
                         title: task.get_task_name().to_string(),
-                        cancellable: Some(false),
+                        cancellable: Some(cancellable),
                         message: Some(task.get_task_name().to_string()),
                         percentage: None,
                     },
@@ -77,15 +141,35 @@ impl StatusBar {
         task: ProgressTask,
         percentage: Option<u32>,
         message: Option<String>,
+    ) {
+        self.report_progress(task.get_token(), false, percentage, message);
+    }
+
+    pub fn update_progress_run(
+        &self,
+        run: &ProgressRun,
+        percentage: Option<u32>,
+        message: Option<String>,
+    ) {
+        let cancellable = !run.cancel_token.is_cancelled();
+        self.report_progress(run.token.clone(), cancellable, percentage, message);
+    }
+
+    fn report_progress(
+        &self,
+        token: NumberOrString,
+        cancellable: bool,
+        percentage: Option<u32>,
+        message: Option<String>,
     ) {
         self.client.send_notification(
             "$/progress",
             ProgressParams {
-                token: NumberOrString::Number(task.as_i32()),
+                token,
                 value: ProgressParamsValue::WorkDone(WorkDoneProgress::Report(
                     WorkDoneProgressReport {
                         percentage,
-                        cancellable: Some(false),
+                        cancellable: Some(cancellable),
                         message,
                     },
                 )),
@@ -94,10 +178,19 @@ impl StatusBar {
     }
 
     pub fn finish_progress_task(&self, task: ProgressTask, message: Option<String>) {
+        self.end_progress(task.get_token(), message);
+    }
+
+    pub fn finish_progress_run(&self, run: &ProgressRun, message: Option<String>) {
+        self.cancellations.lock().unwrap().remove(&run.token);
+        self.end_progress(run.token.clone(), message);
+    }
+
+    fn end_progress(&self, token: NumberOrString, message: Option<String>) {
         self.client.send_notification(
             "$/progress",
             ProgressParams {
-                token: NumberOrString::Number(task.as_i32()),
+                token,
                 value: ProgressParamsValue::WorkDone(WorkDoneProgress::End(WorkDoneProgressEnd {
                     message,
                 })),
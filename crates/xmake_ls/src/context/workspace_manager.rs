@@ -4,24 +4,26 @@ use std::sync::atomic::AtomicBool;
 use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use super::{ClientProxy, FileDiagnostic, StatusBar};
-use crate::handlers::{ClientConfig, init_analysis};
+use crate::handlers::{ClientConfig, init_analysis, send_explorer_changed};
 use dirs;
 use log::info;
-use lsp_types::Uri;
+use lsp_types::{LogMessageParams, MessageType, Uri};
 use tokio::sync::{Mutex, RwLock};
 use tokio_util::sync::CancellationToken;
 use wax::Pattern;
 use xmake_code_analysis::uri_to_file_path;
 use xmake_code_analysis::{Emmyrc, XmakeAnalysis, load_configs};
-use xmake_wrapper::XmakeVersion;
+use xmake_wrapper::{XmakeVersion, XmakeWrapper};
 
 pub struct WorkspaceManager {
     analysis: Arc<RwLock<XmakeAnalysis>>,
-    #[allow(unused)]
     client: Arc<ClientProxy>,
     status_bar: Arc<StatusBar>,
     update_token: Arc<Mutex<Option<Arc<ReindexToken>>>>,
+    compile_commands_token: Arc<Mutex<Option<Arc<ReindexToken>>>>,
+    explorer_token: Arc<Mutex<Option<Arc<ReindexToken>>>>,
     file_diagnostic: Arc<FileDiagnostic>,
+    xmake: Arc<XmakeWrapper>,
     pub client_config: ClientConfig,
     pub workspace_folders: Vec<PathBuf>,
     pub watcher: Option<notify::RecommendedWatcher>,
@@ -38,6 +40,7 @@ impl WorkspaceManager {
         client: Arc<ClientProxy>,
         status_bar: Arc<StatusBar>,
         file_diagnostic: Arc<FileDiagnostic>,
+        xmake: Arc<XmakeWrapper>,
     ) -> Self {
         Self {
             analysis,
@@ -46,7 +49,10 @@ impl WorkspaceManager {
             client_config: ClientConfig::default(),
             workspace_folders: Vec::new(),
             update_token: Arc::new(Mutex::new(None)),
+            compile_commands_token: Arc::new(Mutex::new(None)),
+            explorer_token: Arc::new(Mutex::new(None)),
             file_diagnostic,
+            xmake,
             watcher: None,
             current_open_files: HashSet::new(),
             match_file_pattern: WorkspaceFileMatcher::default(),
@@ -84,6 +90,8 @@ impl WorkspaceManager {
             emmyrc,
         )
         .await;
+        self.update_compile_commands(Duration::ZERO).await;
+        self.notify_explorer_changed(Duration::ZERO).await;
 
         Some(())
     }
@@ -110,6 +118,7 @@ impl WorkspaceManager {
         drop(update_token);
         let analysis = self.analysis.clone();
         let file_diagnostic = self.file_diagnostic.clone();
+        let client = self.client.clone();
         tokio::spawn(async move {
             cancel_token.wait_for_reindex().await;
             if cancel_token.is_cancelled() {
@@ -125,6 +134,92 @@ impl WorkspaceManager {
             file_diagnostic
                 .add_workspace_diagnostic_task(500, true)
                 .await;
+            drop(analysis);
+            send_explorer_changed(&client);
+        });
+
+        Some(())
+    }
+
+    /// Regenerate `compile_commands.json` of every xmake project in the workspace after `delay`,
+    /// a pending update is replaced by the new one.
+    pub async fn update_compile_commands(&self, delay: Duration) -> Option<()> {
+        let mut compile_commands_token = self.compile_commands_token.lock().await;
+        if let Some(token) = compile_commands_token.as_ref() {
+            token.cancel();
+        }
+
+        let cancel_token = Arc::new(ReindexToken::new(delay));
+        compile_commands_token.replace(cancel_token.clone());
+        drop(compile_commands_token);
+        let analysis = self.analysis.clone();
+        let client = self.client.clone();
+        let xmake = self.xmake.clone();
+        let project_dirs = self
+            .workspace_folders
+            .iter()
+            .filter(|workspace| workspace.join("xmake.lua").is_file())
+            .cloned()
+            .collect::<Vec<_>>();
+        tokio::spawn(async move {
+            cancel_token.wait_for_reindex().await;
+            if cancel_token.is_cancelled() {
+                return;
+            }
+
+            let config = analysis.read().await.get_emmyrc().compile_commands.clone();
+            if !config.enable {
+                return;
+            }
+
+            for project_dir in project_dirs {
+                let output_dir = config.output_dir.as_ref().map(PathBuf::from);
+                let result = xmake
+                    .as_ref()
+                    .clone()
+                    .with_working_dir(project_dir.clone())
+                    .compile_commands(output_dir.as_deref())
+                    .execute()
+                    .await;
+                let (typ, message) = match result {
+                    Ok(_) => (
+                        MessageType::INFO,
+                        format!("updated compile_commands.json of {:?}", project_dir),
+                    ),
+                    Err(err) => (
+                        MessageType::WARNING,
+                        format!(
+                            "failed to generate compile_commands.json of {:?}: {}",
+                            project_dir, err
+                        ),
+                    ),
+                };
+                client.log_message(LogMessageParams { typ, message });
+            }
+        });
+
+        Some(())
+    }
+
+    /// Send `xmake/explorerChanged` after `delay`, a pending notification is replaced by the new
+    /// one so typing sends it once
+    pub async fn notify_explorer_changed(&self, delay: Duration) -> Option<()> {
+        let mut explorer_token = self.explorer_token.lock().await;
+        if let Some(token) = explorer_token.as_ref() {
+            token.cancel();
+        }
+
+        let cancel_token = Arc::new(ReindexToken::new(delay));
+        explorer_token.replace(cancel_token.clone());
+        drop(explorer_token);
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            cancel_token.wait_for_reindex().await;
+            if cancel_token.is_cancelled() {
+                return;
+            }
+
+            send_explorer_changed(&client);
         });
 
         Some(())
@@ -153,6 +248,17 @@ impl WorkspaceManager {
 
         file_matched
     }
+
+    /// The directory xmake commands for `file_path` run in: the innermost workspace folder that
+    /// contains the file, or the directory of the file itself.
+    pub fn get_project_dir(&self, file_path: &Path) -> Option<PathBuf> {
+        self.workspace_folders
+            .iter()
+            .filter(|workspace| file_path.starts_with(workspace))
+            .max_by_key(|workspace| workspace.components().count())
+            .cloned()
+            .or_else(|| file_path.parent().map(|parent| parent.to_path_buf()))
+    }
 }
 
 pub fn load_emmy_config(config_root: Option<PathBuf>, client_config: ClientConfig) -> Arc<Emmyrc> {
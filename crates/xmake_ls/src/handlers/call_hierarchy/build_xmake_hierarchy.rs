@@ -0,0 +1,290 @@
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallArgList, LuaCallExpr, LuaExpr, LuaLiteralExpr, LuaLiteralToken,
+    LuaStringToken, LuaSyntaxToken,
+};
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Position, Range,
+    SymbolKind,
+};
+use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use xmake_code_analysis::{
+    DbIndex, FileId, SemanticModel, XmakeFunction, XmakeTarget, get_xmake_function,
+};
+
+/// Call hierarchy data of a target (its deps) or of an xmake file (its `includes`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "xmake", rename_all = "camelCase")]
+pub enum XmakeHierarchyItemData {
+    Target { name: String },
+    File { file_id: FileId },
+}
+
+/// The item for the name string of `target("x")` or a path string of `includes(...)`
+pub fn build_xmake_hierarchy_item(
+    semantic_model: &SemanticModel,
+    token: LuaSyntaxToken,
+) -> Option<CallHierarchyItem> {
+    let string_token = LuaStringToken::cast(token)?;
+    let call_expr = string_token
+        .get_parent::<LuaLiteralExpr>()?
+        .get_parent::<LuaCallArgList>()?
+        .get_parent::<LuaCallExpr>()?;
+    let db = semantic_model.get_db();
+    match get_xmake_function(&call_expr)? {
+        XmakeFunction::Target => {
+            build_target_item(db, &string_token.get_value()).map(|(_, item)| item)
+        }
+        XmakeFunction::Includes => {
+            let module_info = db.get_module_index().find_include(
+                db,
+                &string_token.get_value(),
+                semantic_model.get_file_id(),
+            )?;
+            build_file_item(db, module_info.file_id)
+        }
+        _ => None,
+    }
+}
+
+/// Targets depending on a target, files including a file
+pub fn build_xmake_incoming_hierarchy(
+    db: &DbIndex,
+    data: XmakeHierarchyItemData,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let mut result = vec![];
+    match data {
+        XmakeHierarchyItemData::Target { name } => {
+            let mut dependents: Vec<&str> = vec![];
+            for (_, target) in get_sorted_targets(db) {
+                let is_dependent = target.properties.deps.iter().any(|dep| dep.value == name);
+                if is_dependent && !dependents.contains(&target.name.as_str()) {
+                    dependents.push(&target.name);
+                }
+            }
+
+            for dependent in dependents {
+                let Some((item_file_id, from)) = build_target_item(db, dependent) else {
+                    continue;
+                };
+                // ranges are relative to the item, which is the first declaration
+                let ranges = get_sorted_targets(db)
+                    .into_iter()
+                    .filter(|(file_id, target)| {
+                        *file_id == item_file_id && target.name == dependent
+                    })
+                    .flat_map(|(_, target)| target.properties.deps.iter())
+                    .filter(|dep| dep.value == name)
+                    .map(|dep| dep.range)
+                    .collect::<Vec<_>>();
+                result.push(CallHierarchyIncomingCall {
+                    from_ranges: to_lsp_ranges(db, item_file_id, &ranges),
+                    from,
+                });
+            }
+        }
+        XmakeHierarchyItemData::File { file_id } => {
+            let mut includers = db
+                .get_vfs()
+                .get_all_file_ids()
+                .into_iter()
+                .filter(|includer| {
+                    db.get_xmake_index()
+                        .get_includes(*includer)
+                        .is_some_and(|includes| includes.contains(&file_id))
+                })
+                .collect::<Vec<_>>();
+            includers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            for includer in includers {
+                let ranges = get_include_ranges(db, includer)
+                    .into_iter()
+                    .filter(|(include_file_id, _)| *include_file_id == file_id)
+                    .map(|(_, range)| range)
+                    .collect::<Vec<_>>();
+                let Some(from) = build_file_item(db, includer) else {
+                    continue;
+                };
+                result.push(CallHierarchyIncomingCall {
+                    from_ranges: to_lsp_ranges(db, includer, &ranges),
+                    from,
+                });
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Targets a target depends on, files a file includes
+pub fn build_xmake_outgoing_hierarchy(
+    db: &DbIndex,
+    data: XmakeHierarchyItemData,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let mut result = vec![];
+    match data {
+        XmakeHierarchyItemData::Target { name } => {
+            let declarations = get_sorted_targets(db)
+                .into_iter()
+                .filter(|(_, target)| target.name == name)
+                .collect::<Vec<_>>();
+            // ranges are relative to the item, which is the first declaration
+            let item_file_id = declarations.first()?.0;
+            let mut deps: Vec<(&str, Vec<TextRange>)> = vec![];
+            for (file_id, target) in &declarations {
+                for dep in &target.properties.deps {
+                    let index = match deps.iter().position(|(name, _)| *name == dep.value) {
+                        Some(index) => index,
+                        None => {
+                            deps.push((&dep.value, vec![]));
+                            deps.len() - 1
+                        }
+                    };
+                    if *file_id == item_file_id {
+                        deps[index].1.push(dep.range);
+                    }
+                }
+            }
+
+            for (dep_name, ranges) in deps {
+                // packages from a repository or targets of another project
+                let Some((_, to)) = build_target_item(db, dep_name) else {
+                    continue;
+                };
+                result.push(CallHierarchyOutgoingCall {
+                    to,
+                    from_ranges: to_lsp_ranges(db, item_file_id, &ranges),
+                });
+            }
+        }
+        XmakeHierarchyItemData::File { file_id } => {
+            let include_ranges = get_include_ranges(db, file_id);
+            let Some(includes) = db.get_xmake_index().get_includes(file_id) else {
+                return Some(result);
+            };
+            let mut visited = vec![];
+            for include_file_id in includes {
+                if visited.contains(include_file_id) {
+                    continue;
+                }
+                visited.push(*include_file_id);
+
+                let ranges = include_ranges
+                    .iter()
+                    .filter(|(id, _)| id == include_file_id)
+                    .map(|(_, range)| *range)
+                    .collect::<Vec<_>>();
+                let Some(to) = build_file_item(db, *include_file_id) else {
+                    continue;
+                };
+                result.push(CallHierarchyOutgoingCall {
+                    to,
+                    from_ranges: to_lsp_ranges(db, file_id, &ranges),
+                });
+            }
+        }
+    }
+
+    Some(result)
+}
+
+/// Targets in a stable order, declarations of the same target in `is_plat` branches are kept
+fn get_sorted_targets(db: &DbIndex) -> Vec<(FileId, &XmakeTarget)> {
+    let mut targets = db
+        .get_xmake_index()
+        .get_all_targets()
+        .filter(|(_, target)| target.kind.is_target())
+        .collect::<Vec<_>>();
+    targets.sort_by(|(a_file, a), (b_file, b)| {
+        a_file
+            .partial_cmp(b_file)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.name_range.start().cmp(&b.name_range.start()))
+    });
+    targets
+}
+
+/// The item of a target and the file of its first declaration
+fn build_target_item(db: &DbIndex, name: &str) -> Option<(FileId, CallHierarchyItem)> {
+    let (file_id, target) = get_sorted_targets(db)
+        .into_iter()
+        .find(|(_, target)| target.name == name)?;
+    let document = db.get_vfs().get_document(&file_id)?;
+
+    let item = CallHierarchyItem {
+        name: target.name.clone(),
+        kind: SymbolKind::CLASS,
+        tags: None,
+        detail: target
+            .properties
+            .kind
+            .as_ref()
+            .map(|kind| kind.value.clone()),
+        uri: document.get_uri(),
+        range: document.to_lsp_range(target.range)?,
+        selection_range: document.to_lsp_range(target.name_range)?,
+        data: Some(
+            serde_json::to_value(XmakeHierarchyItemData::Target {
+                name: target.name.clone(),
+            })
+            .ok()?,
+        ),
+    };
+    Some((file_id, item))
+}
+
+fn build_file_item(db: &DbIndex, file_id: FileId) -> Option<CallHierarchyItem> {
+    let document = db.get_vfs().get_document(&file_id)?;
+    let start = Range::new(Position::new(0, 0), Position::new(0, 0));
+
+    Some(CallHierarchyItem {
+        name: document.get_file_name()?,
+        kind: SymbolKind::FILE,
+        tags: None,
+        detail: db
+            .get_vfs()
+            .get_file_path(&file_id)
+            .map(|path| path.to_string_lossy().to_string()),
+        uri: document.get_uri(),
+        range: document.get_document_lsp_range(),
+        selection_range: start,
+        data: Some(serde_json::to_value(XmakeHierarchyItemData::File { file_id }).ok()?),
+    })
+}
+
+/// The path strings of the `includes` calls of a file and the files they resolve to
+fn get_include_ranges(db: &DbIndex, file_id: FileId) -> Vec<(FileId, TextRange)> {
+    let mut result = vec![];
+    let Some(tree) = db.get_vfs().get_syntax_tree(&file_id) else {
+        return result;
+    };
+    for call_expr in tree.get_chunk_node().descendants::<LuaCallExpr>() {
+        if get_xmake_function(&call_expr) != Some(XmakeFunction::Includes) {
+            continue;
+        }
+        let Some(args_list) = call_expr.get_args_list() else {
+            continue;
+        };
+        for arg in args_list.get_args() {
+            if let LuaExpr::LiteralExpr(literal) = arg
+                && let Some(LuaLiteralToken::String(string_token)) = literal.get_literal()
+                && let Some(module_info) =
+                    db.get_module_index()
+                        .find_include(db, &string_token.get_value(), file_id)
+            {
+                result.push((module_info.file_id, string_token.get_range()));
+            }
+        }
+    }
+
+    result
+}
+
+fn to_lsp_ranges(db: &DbIndex, file_id: FileId, ranges: &[TextRange]) -> Vec<Range> {
+    let Some(document) = db.get_vfs().get_document(&file_id) else {
+        return vec![];
+    };
+    ranges
+        .iter()
+        .filter_map(|range| document.to_lsp_range(*range))
+        .collect()
+}
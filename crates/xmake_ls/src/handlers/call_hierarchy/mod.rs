@@ -1,8 +1,13 @@
 mod build_call_hierarchy;
+mod build_xmake_hierarchy;
 
 use build_call_hierarchy::{
     CallHierarchyItemData, build_call_hierarchy_item, build_incoming_hierarchy,
 };
+pub use build_xmake_hierarchy::{
+    XmakeHierarchyItemData, build_xmake_hierarchy_item, build_xmake_incoming_hierarchy,
+    build_xmake_outgoing_hierarchy,
+};
 use emmylua_parser::{LuaAstNode, LuaTokenKind};
 use lsp_types::{
     CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
@@ -51,6 +56,10 @@ pub async fn on_prepare_call_hierarchy_handler(
         }
     };
 
+    if let Some(item) = build_xmake_hierarchy_item(&semantic_model, token.clone()) {
+        return Some(vec![item]);
+    }
+
     let semantic_decl =
         semantic_model.find_decl(token.clone().into(), SemanticDeclLevel::default())?;
 
@@ -67,8 +76,12 @@ pub async fn on_incoming_calls_handler(
 ) -> Option<Vec<CallHierarchyIncomingCall>> {
     let item = params.item;
     let data = item.data.as_ref()?;
-    let data = serde_json::from_value::<CallHierarchyItemData>(data.clone()).ok()?;
     let analysis = context.analysis().read().await;
+    if let Ok(data) = serde_json::from_value::<XmakeHierarchyItemData>(data.clone()) {
+        return build_xmake_incoming_hierarchy(analysis.compilation.get_db(), data);
+    }
+
+    let data = serde_json::from_value::<CallHierarchyItemData>(data.clone()).ok()?;
     let semantic_model = analysis.compilation.get_semantic_model(data.file_id)?;
     let semantic_decl_id = data.semantic_decl;
 
@@ -76,11 +89,15 @@ pub async fn on_incoming_calls_handler(
 }
 
 pub async fn on_outgoing_calls_handler(
-    _: ServerContextSnapshot,
-    _: CallHierarchyOutgoingCallsParams,
+    context: ServerContextSnapshot,
+    params: CallHierarchyOutgoingCallsParams,
     _: CancellationToken,
 ) -> Option<Vec<CallHierarchyOutgoingCall>> {
-    None
+    // outgoing calls of lua functions are not supported yet
+    let data = params.item.data.as_ref()?;
+    let data = serde_json::from_value::<XmakeHierarchyItemData>(data.clone()).ok()?;
+    let analysis = context.analysis().read().await;
+    build_xmake_outgoing_hierarchy(analysis.compilation.get_db(), data)
 }
 
 pub struct CallHierarchyCapabilities;
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::handlers::command::make_auto_doc_tag_command;
+use crate::handlers::command::{make_auto_doc_tag_command, make_fix_format_command};
 use emmylua_parser::{LuaAstNode, LuaExpr};
 use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, WorkspaceEdit};
 use rowan::{NodeOrToken, TokenAtOffset};
@@ -79,3 +79,26 @@ pub fn build_add_doc_tag(
 
     Some(())
 }
+
+pub fn build_fix_format(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+    data: &Option<serde_json::Value>,
+) -> Option<()> {
+    let new_text = data.as_ref()?.as_str()?;
+    let title = t!("Fix formatting here").to_string();
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: title.clone(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        command: Some(make_fix_format_command(
+            &title,
+            semantic_model.get_document().get_uri(),
+            range,
+            new_text,
+        )),
+        ..Default::default()
+    }));
+
+    Some(())
+}
@@ -0,0 +1,824 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallExpr, LuaCallExprStat, LuaComment, LuaExpr, LuaIndexKey,
+    LuaLiteralToken, LuaStat,
+};
+use lsp_types::{
+    ClientCapabilities, CodeAction, CodeActionKind, CodeActionOrCommand, CreateFile,
+    CreateFileOptions, DocumentChangeOperation, DocumentChanges, OneOf,
+    OptionalVersionedTextDocumentIdentifier, Position, Range, ResourceOp, ResourceOperationKind,
+    TextDocumentEdit, TextEdit, WorkspaceEdit,
+};
+use rowan::{Direction, TextRange, TextSize};
+use xmake_code_analysis::{
+    LuaDocument, SemanticModel, XmakeFunction, XmakeTarget, XmakeTargetKind, file_path_to_uri,
+    get_xmake_function,
+};
+
+const INDENT: &str = "    ";
+
+/// Apis taking paths relative to the script directory, rewritten when a target is moved
+const PATH_APIS: &[&str] = &[
+    "add_files",
+    "add_headerfiles",
+    "add_installfiles",
+    "add_extrafiles",
+    "add_includedirs",
+    "add_sysincludedirs",
+    "add_linkdirs",
+    "add_rpathdirs",
+    "set_pcheader",
+    "set_pcxxheader",
+];
+
+/// A target declaration at the cursor
+struct TargetDeclaration<'a> {
+    stat: LuaCallExprStat,
+    call_expr: LuaCallExpr,
+    target: &'a XmakeTarget,
+}
+
+impl TargetDeclaration<'_> {
+    fn is_table_style(&self) -> bool {
+        self.call_expr
+            .get_args_list()
+            .is_some_and(|args_list| args_list.get_args().count() > 1)
+    }
+
+    /// The name string as written, keeping its quotes and escapes
+    fn get_name_text(&self, document: &LuaDocument) -> String {
+        document.get_text_slice(self.target.name_range).to_string()
+    }
+
+    /// The end of the declaration, including `target_end()`
+    fn get_end(&self, document: &LuaDocument) -> TextSize {
+        if self.is_table_style() {
+            return self.stat.get_range().end();
+        }
+
+        match self.target.end_call_range {
+            Some(end_call_range) => end_call_range.end(),
+            None => {
+                let text = document.get_text_slice(self.target.range);
+                self.target.range.start() + TextSize::from(text.trim_end().len() as u32)
+            }
+        }
+    }
+
+    fn get_body_stats(&self) -> Vec<LuaStat> {
+        let end = match self.target.end_call_range {
+            Some(end_call_range) => end_call_range.start(),
+            None => self.target.range.end(),
+        };
+        self.stat
+            .syntax()
+            .siblings(Direction::Next)
+            .skip(1)
+            .filter_map(LuaStat::cast)
+            .take_while(|stat| stat.get_range().start() < end)
+            .collect()
+    }
+}
+
+pub fn build_xmake_refactor_actions(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    range: Range,
+    client_capabilities: &ClientCapabilities,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let selection = document.to_rowan_range(range)?;
+
+    if let Some(declaration) = find_target_declaration(semantic_model, selection.start()) {
+        if declaration.is_table_style() {
+            build_convert_to_block(semantic_model, actions, &declaration);
+        } else {
+            build_convert_to_table(semantic_model, actions, &declaration);
+        }
+        if supports_create_file(client_capabilities) {
+            build_move_target(semantic_model, actions, &declaration);
+        }
+    }
+
+    if !selection.is_empty() {
+        build_extract_common_settings(semantic_model, actions, selection);
+        build_wrap_is_plat(semantic_model, actions, selection);
+    }
+
+    Some(())
+}
+
+fn find_target_declaration<'a>(
+    semantic_model: &'a SemanticModel,
+    offset: TextSize,
+) -> Option<TargetDeclaration<'a>> {
+    let token_at_offset = semantic_model.get_root().syntax().token_at_offset(offset);
+    let call_expr = token_at_offset.into_iter().find_map(|token| {
+        token
+            .parent_ancestors()
+            .filter_map(LuaCallExpr::cast)
+            .find(|call_expr| get_xmake_function(call_expr) == Some(XmakeFunction::Target))
+    })?;
+    let stat = call_expr.get_parent::<LuaCallExprStat>()?;
+    let LuaExpr::LiteralExpr(literal) = call_expr.get_args_list()?.get_args().next()? else {
+        return None;
+    };
+    let LuaLiteralToken::String(string_token) = literal.get_literal()? else {
+        return None;
+    };
+
+    let target = semantic_model
+        .get_db()
+        .get_xmake_index()
+        .get_targets(semantic_model.get_file_id())?
+        .iter()
+        .find(|target| {
+            target.kind == XmakeTargetKind::Target && target.name_range == string_token.get_range()
+        })?;
+    Some(TargetDeclaration {
+        stat,
+        call_expr,
+        target,
+    })
+}
+
+/// `target("a", {kind = "binary", files = {"a.c"}})` to `target("a") set_kind(...) target_end()`
+fn build_convert_to_block(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    declaration: &TargetDeclaration,
+) -> Option<()> {
+    let LuaExpr::TableExpr(table_expr) =
+        declaration.call_expr.get_args_list()?.get_args().nth(1)?
+    else {
+        return None;
+    };
+
+    let document = semantic_model.get_document();
+    let globals = semantic_model.get_db().get_global_index();
+    let mut lines = Vec::new();
+    for field in table_expr.get_fields() {
+        let LuaIndexKey::Name(name) = field.get_field_key()? else {
+            return None;
+        };
+        let key = name.get_name_text();
+        let value = field.get_value_expr()?;
+        if is_script_api(key) {
+            lines.push(format!("{}({})", key, value.syntax().text()));
+            continue;
+        }
+
+        let (values, is_list) = match &value {
+            LuaExpr::TableExpr(list) => {
+                let mut values = Vec::new();
+                for item in list.get_fields() {
+                    if !item.is_value_field() {
+                        return None;
+                    }
+                    values.push(item.get_value_expr()?.syntax().text().to_string());
+                }
+                (values, true)
+            }
+            _ => (vec![value.syntax().text().to_string()], false),
+        };
+        // prefer the api xmake has, the shape of the value decides otherwise
+        let set_name = format!("set_{}", key);
+        let add_name = format!("add_{}", key);
+        let api_name = match (
+            globals.is_exist_global_decl(&set_name),
+            globals.is_exist_global_decl(&add_name),
+        ) {
+            (true, false) => set_name,
+            (false, true) => add_name,
+            _ if is_list => add_name,
+            _ => set_name,
+        };
+        lines.push(format!("{}({})", api_name, values.join(", ")));
+    }
+
+    let indent = get_indent(&document, declaration.stat.get_range().start());
+    let mut new_text = format!("target({})\n", declaration.get_name_text(&document));
+    for line in lines {
+        new_text.push_str(&format!("{}{}{}\n", indent, INDENT, line));
+    }
+    new_text.push_str(&format!("{}target_end()", indent));
+
+    push_edit_action(
+        semantic_model,
+        actions,
+        t!("Convert to block style target").to_string(),
+        CodeActionKind::REFACTOR_REWRITE,
+        vec![TextEdit {
+            range: document.to_lsp_range(declaration.stat.get_range())?,
+            new_text,
+        }],
+    )
+}
+
+enum TableField {
+    Script(String, String),
+    Values {
+        key: String,
+        values: Vec<String>,
+        is_list: bool,
+    },
+}
+
+/// `target("a") set_kind("binary") add_files("a.c")` to `target("a", {kind = "binary", ...})`
+fn build_convert_to_table(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    declaration: &TargetDeclaration,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let end = declaration.get_end(&document);
+    let range = TextRange::new(declaration.stat.get_range().start(), end);
+    // a table has nowhere to keep comments
+    let has_comment = semantic_model
+        .get_root()
+        .descendants::<LuaComment>()
+        .any(|comment| range.contains_range(comment.get_range()));
+    if has_comment {
+        return None;
+    }
+
+    let mut fields: Vec<TableField> = Vec::new();
+    for stat in declaration.get_body_stats() {
+        let LuaStat::CallExprStat(call_stat) = stat else {
+            return None;
+        };
+        let call_expr = call_stat.get_call_expr()?;
+        let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+            return None;
+        };
+        let name = name_expr.get_name_text()?;
+        let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+        if is_script_api(&name) {
+            let [script] = args.as_slice() else {
+                return None;
+            };
+            fields.push(TableField::Script(name, script.syntax().text().to_string()));
+            continue;
+        }
+
+        let (key, is_list) = if let Some(key) = name.strip_prefix("set_") {
+            (key, args.len() > 1)
+        } else if let Some(key) = name.strip_prefix("add_") {
+            (key, true)
+        } else {
+            return None;
+        };
+        // extra configs like `add_files("a.c", {defines = "A"})` have no table form
+        if args.is_empty()
+            || args
+                .iter()
+                .any(|arg| matches!(arg, LuaExpr::TableExpr(_) | LuaExpr::ClosureExpr(_)))
+        {
+            return None;
+        }
+        let values = args
+            .iter()
+            .map(|arg| arg.syntax().text().to_string())
+            .collect::<Vec<_>>();
+
+        let existing = fields.iter_mut().find_map(|field| match field {
+            TableField::Values {
+                key: existing_key,
+                values,
+                is_list,
+            } if existing_key == key => Some((values, *is_list)),
+            _ => None,
+        });
+        match existing {
+            Some((existing_values, true)) if name.starts_with("add_") => {
+                existing_values.extend(values);
+            }
+            Some(_) => return None,
+            None => fields.push(TableField::Values {
+                key: key.to_string(),
+                values,
+                is_list,
+            }),
+        }
+    }
+
+    let indent = get_indent(&document, declaration.stat.get_range().start());
+    let new_text = if fields.is_empty() {
+        format!("target({}, {{}})", declaration.get_name_text(&document))
+    } else {
+        let mut new_text = format!("target({}, {{\n", declaration.get_name_text(&document));
+        for field in fields {
+            let field_text = match field {
+                TableField::Script(name, script) => format!("{} = {}", name, script),
+                TableField::Values {
+                    key,
+                    values,
+                    is_list: false,
+                } => format!("{} = {}", key, values.join(", ")),
+                TableField::Values { key, values, .. } => {
+                    format!("{} = {{{}}}", key, values.join(", "))
+                }
+            };
+            new_text.push_str(&format!("{}{}{},\n", indent, INDENT, field_text));
+        }
+        new_text.push_str(&format!("{}}})", indent));
+        new_text
+    };
+
+    push_edit_action(
+        semantic_model,
+        actions,
+        t!("Convert to table style target").to_string(),
+        CodeActionKind::REFACTOR_REWRITE,
+        vec![TextEdit {
+            range: document.to_lsp_range(range)?,
+            new_text,
+        }],
+    )
+}
+
+/// Move the `add_*` calls all selected targets share into a rule or a loop over the targets
+fn build_extract_common_settings(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    selection: TextRange,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let declarations = semantic_model
+        .get_root()
+        .descendants::<LuaCallExprStat>()
+        .filter(|stat| selection.contains_range(stat.get_range()))
+        .filter_map(|stat| find_target_declaration(semantic_model, stat.get_range().start()))
+        .filter(|declaration| !declaration.is_table_style())
+        .collect::<Vec<_>>();
+    if declarations.len() < 2 {
+        return None;
+    }
+
+    let bodies = declarations
+        .iter()
+        .map(|declaration| {
+            declaration
+                .get_body_stats()
+                .into_iter()
+                .filter_map(|stat| {
+                    let LuaStat::CallExprStat(call_stat) = stat else {
+                        return None;
+                    };
+                    let call_expr = call_stat.get_call_expr()?;
+                    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+                        return None;
+                    };
+                    if !name_expr.get_name_text()?.starts_with("add_") {
+                        return None;
+                    }
+                    Some((normalize_text(&call_stat), call_stat))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut common: Vec<(String, LuaCallExprStat)> = Vec::new();
+    for (text, stat) in &bodies[0] {
+        let is_common = bodies[1..]
+            .iter()
+            .all(|body| body.iter().any(|(other, _)| other == text));
+        if is_common && !common.iter().any(|(existing, _)| existing == text) {
+            common.push((text.clone(), stat.clone()));
+        }
+    }
+    if common.is_empty() {
+        return None;
+    }
+
+    let common_stats = |body: &Vec<(String, LuaCallExprStat)>| {
+        body.iter()
+            .filter(|(text, _)| common.iter().any(|(common_text, _)| common_text == text))
+            .map(|(_, stat)| stat.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // into a rule, each target gets `add_rules` in place of the first removed call
+    let rule_name = get_free_rule_name(semantic_model);
+    let first = &declarations[0];
+    let indent = get_indent(&document, first.stat.get_range().start());
+    let mut rule_text = format!(
+        "{}rule(\"{}\")\n{}{}on_load(function (target)\n",
+        indent, rule_name, indent, INDENT
+    );
+    for (_, stat) in &common {
+        rule_text.push_str(&format!(
+            "{}{}{}{}\n",
+            indent,
+            INDENT,
+            INDENT,
+            to_target_add(stat)?
+        ));
+    }
+    rule_text.push_str(&format!(
+        "{}{}end)\n{}rule_end()\n\n",
+        indent, INDENT, indent
+    ));
+
+    let mut rule_edits = vec![TextEdit {
+        range: get_line_start_range(&document, first.stat.get_range().start())?,
+        new_text: rule_text,
+    }];
+    let mut loop_edits = Vec::new();
+    for body in &bodies {
+        let stats = common_stats(body);
+        for (i, stat) in stats.iter().enumerate() {
+            let delete_range = get_stat_delete_range(&document, stat.get_range())?;
+            if i == 0 {
+                rule_edits.push(TextEdit {
+                    range: document.to_lsp_range(stat.get_range())?,
+                    new_text: format!("add_rules(\"{}\")", rule_name),
+                });
+            } else {
+                rule_edits.push(TextEdit {
+                    range: delete_range,
+                    new_text: String::new(),
+                });
+            }
+            loop_edits.push(TextEdit {
+                range: delete_range,
+                new_text: String::new(),
+            });
+        }
+    }
+    push_edit_action(
+        semantic_model,
+        actions,
+        t!(
+            "Extract common settings into rule %{name}",
+            name = rule_name
+        )
+        .to_string(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        rule_edits,
+    );
+
+    // into a loop, reopening each target after the last one
+    let last = declarations.last()?;
+    let names = declarations
+        .iter()
+        .map(|declaration| declaration.get_name_text(&document))
+        .collect::<Vec<_>>();
+    let mut loop_text = format!(
+        "\n\n{}for _, name in ipairs({{{}}}) do\n{}{}target(name)\n",
+        indent,
+        names.join(", "),
+        indent,
+        INDENT
+    );
+    for (_, stat) in &common {
+        loop_text.push_str(&format!(
+            "{}{}{}{}\n",
+            indent,
+            INDENT,
+            INDENT,
+            normalize_text(stat)
+        ));
+    }
+    loop_text.push_str(&format!("{}{}target_end()\n{}end", indent, INDENT, indent));
+    let end_position = document.to_lsp_position(last.get_end(&document))?;
+    loop_edits.push(TextEdit {
+        range: Range::new(end_position, end_position),
+        new_text: loop_text,
+    });
+    push_edit_action(
+        semantic_model,
+        actions,
+        t!("Extract common settings into a loop").to_string(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        loop_edits,
+    )
+}
+
+/// Wrap the selected statements in `if is_plat(<host>) then ... end`
+fn build_wrap_is_plat(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    selection: TextRange,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let token = semantic_model
+        .get_root()
+        .syntax()
+        .token_at_offset(selection.start())
+        .right_biased()?;
+    let first = token
+        .parent_ancestors()
+        .filter_map(LuaStat::cast)
+        .find(|stat| stat.get_range().start() >= selection.start())
+        .or_else(|| {
+            // the selection starts in the indentation before a statement
+            token
+                .next_sibling_or_token()?
+                .into_node()
+                .and_then(LuaStat::cast)
+        })?;
+    let mut stats = vec![first.clone()];
+    stats.extend(
+        first
+            .syntax()
+            .siblings(Direction::Next)
+            .skip(1)
+            .filter_map(LuaStat::cast)
+            .take_while(|stat| stat.get_range().start() < selection.end()),
+    );
+    let range = TextRange::new(first.get_range().start(), stats.last()?.get_range().end());
+
+    let plat = get_host_plat();
+    let indent = get_indent(&document, range.start());
+    let mut new_text = format!("if is_plat(\"{}\") then\n", plat);
+    for (i, line) in document.get_text_slice(range).lines().enumerate() {
+        if line.trim().is_empty() {
+            new_text.push('\n');
+        } else if i == 0 {
+            new_text.push_str(&format!("{}{}{}\n", indent, INDENT, line));
+        } else {
+            new_text.push_str(&format!("{}{}\n", INDENT, line));
+        }
+    }
+    new_text.push_str(&format!("{}end", indent));
+
+    push_edit_action(
+        semantic_model,
+        actions,
+        t!("Wrap in is_plat(%{plat}) check", plat = plat).to_string(),
+        CodeActionKind::REFACTOR_REWRITE,
+        vec![TextEdit {
+            range: document.to_lsp_range(range)?,
+            new_text,
+        }],
+    )
+}
+
+/// Move a target into `<name>/xmake.lua` and include it in its place
+fn build_move_target(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    declaration: &TargetDeclaration,
+) -> Option<()> {
+    let name = &declaration.target.name;
+    let is_valid_dir = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && name != "."
+        && name != "..";
+    if !is_valid_dir {
+        return None;
+    }
+
+    let document = semantic_model.get_document();
+    let new_path = document
+        .get_file_path()
+        .parent()?
+        .join(name)
+        .join("xmake.lua");
+    if new_path.exists() {
+        return None;
+    }
+    let new_uri = file_path_to_uri(&new_path)?;
+
+    let start = declaration.stat.get_range().start();
+    let range = TextRange::new(start, declaration.get_end(&document));
+    // paths are relative to the directory of the script
+    let mut path_edits = Vec::new();
+    for call_expr in semantic_model.get_root().descendants::<LuaCallExpr>() {
+        if !range.contains_range(call_expr.get_range()) {
+            continue;
+        }
+        let Some(LuaExpr::NameExpr(name_expr)) = call_expr.get_prefix_expr() else {
+            continue;
+        };
+        if !name_expr
+            .get_name_text()
+            .is_some_and(|name| PATH_APIS.contains(&name.as_str()))
+        {
+            continue;
+        }
+        let Some(args_list) = call_expr.get_args_list() else {
+            continue;
+        };
+        for arg in args_list.get_args() {
+            if let LuaExpr::LiteralExpr(literal) = arg
+                && let Some(LuaLiteralToken::String(string_token)) = literal.get_literal()
+                && is_relative_path(&string_token.get_value())
+            {
+                let text = string_token.get_text();
+                path_edits.push(string_token.get_range().start() + get_string_content_start(text));
+            }
+        }
+    }
+
+    let mut text = document.get_text_slice(range).to_string();
+    for offset in path_edits.iter().rev() {
+        text.insert_str(u32::from(*offset - start) as usize, "../");
+    }
+    let indent = get_indent(&document, start);
+    let new_content = text
+        .lines()
+        .map(|line| line.strip_prefix(indent.as_str()).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n";
+
+    let origin_edit = TextEdit {
+        range: document.to_lsp_range(range)?,
+        new_text: format!("includes(\"{}\")", name),
+    };
+    let document_changes = vec![
+        DocumentChangeOperation::Op(ResourceOp::Create(CreateFile {
+            uri: new_uri.clone(),
+            options: Some(CreateFileOptions {
+                overwrite: Some(false),
+                ignore_if_exists: Some(true),
+            }),
+            annotation_id: None,
+        })),
+        DocumentChangeOperation::Edit(TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier {
+                uri: new_uri,
+                version: None,
+            },
+            edits: vec![OneOf::Left(TextEdit {
+                range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+                new_text: new_content,
+            })],
+        }),
+        DocumentChangeOperation::Edit(TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier {
+                uri: document.get_uri(),
+                version: None,
+            },
+            edits: vec![OneOf::Left(origin_edit)],
+        }),
+    ];
+
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: t!(
+            "Move target to %{path}",
+            path = format!("{}/xmake.lua", name)
+        )
+        .to_string(),
+        kind: Some(CodeActionKind::REFACTOR),
+        edit: Some(WorkspaceEdit {
+            document_changes: Some(DocumentChanges::Operations(document_changes)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }));
+    Some(())
+}
+
+fn push_edit_action(
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    title: String,
+    kind: CodeActionKind,
+    edits: Vec<TextEdit>,
+) -> Option<()> {
+    let uri = semantic_model.get_document().get_uri();
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(kind),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, edits)])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }));
+    Some(())
+}
+
+fn is_script_api(name: &str) -> bool {
+    name.starts_with("on_") || name.starts_with("before_") || name.starts_with("after_")
+}
+
+/// The `workspace/applyEdit` of the client can create the file a target is moved to
+fn supports_create_file(client_capabilities: &ClientCapabilities) -> bool {
+    client_capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.workspace_edit.as_ref())
+        .is_some_and(|workspace_edit| {
+            workspace_edit.document_changes == Some(true)
+                && workspace_edit
+                    .resource_operations
+                    .as_ref()
+                    .is_some_and(|kinds| kinds.contains(&ResourceOperationKind::Create))
+        })
+}
+
+/// The offset of the content in the text of a string token, after `"`, `'` or `[==[` and the
+/// newline Lua skips right after a long bracket
+fn get_string_content_start(text: &str) -> TextSize {
+    let mut start = match text.strip_prefix('[') {
+        Some(rest) => rest.find('[').map_or(1, |pos| pos + 2),
+        None => 1,
+    };
+    if text.starts_with('[') {
+        if text[start..].starts_with("\r\n") {
+            start += 2;
+        } else if text[start..].starts_with('\n') {
+            start += 1;
+        }
+    }
+    TextSize::from(start as u32)
+}
+
+fn is_relative_path(path: &str) -> bool {
+    !(path.starts_with('/') || path.starts_with("$(") || path.contains(':'))
+}
+
+/// `add_defines("A", "B")` to `target:add("defines", "A", "B")`
+fn to_target_add(stat: &LuaCallExprStat) -> Option<String> {
+    let call_expr = stat.get_call_expr()?;
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    let name = name_expr.get_name_text()?;
+    let mut args = vec![format!("\"{}\"", name.strip_prefix("add_")?)];
+    args.extend(
+        call_expr
+            .get_args_list()?
+            .get_args()
+            .map(|arg| arg.syntax().text().to_string()),
+    );
+    Some(format!("target:add({})", args.join(", ")))
+}
+
+fn get_free_rule_name(semantic_model: &SemanticModel) -> String {
+    let xmake_index = semantic_model.get_db().get_xmake_index();
+    let mut name = "common".to_string();
+    let mut i = 1;
+    while xmake_index
+        .find_targets(&name, XmakeTargetKind::Rule)
+        .next()
+        .is_some()
+    {
+        i += 1;
+        name = format!("common{}", i);
+    }
+    name
+}
+
+fn normalize_text(stat: &LuaCallExprStat) -> String {
+    stat.syntax()
+        .text()
+        .to_string()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn get_indent(document: &LuaDocument, offset: TextSize) -> String {
+    let Some(line_range) = document
+        .get_line(offset)
+        .and_then(|line| document.get_line_range(line))
+    else {
+        return String::new();
+    };
+    document
+        .get_text_slice(line_range)
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+fn get_line_start_range(document: &LuaDocument, offset: TextSize) -> Option<Range> {
+    let line = document.get_line(offset)?;
+    let position = Position::new(line as u32, 0);
+    Some(Range::new(position, position))
+}
+
+/// From the end of the previous line to the end of `range`, deleting a statement with its line
+/// without touching the text after it
+fn get_stat_delete_range(document: &LuaDocument, range: TextRange) -> Option<Range> {
+    let line = document.get_line(range.start())?;
+    let line_start = usize::from(document.get_line_range(line)?.start());
+    let before = &document.get_text()[..line_start];
+    let newline_len = if before.ends_with("\r\n") {
+        2
+    } else if before.ends_with('\n') {
+        1
+    } else {
+        return None;
+    };
+    let start = TextSize::from((line_start - newline_len) as u32);
+    document.to_lsp_range(TextRange::new(start, range.end()))
+}
+
+fn get_host_plat() -> &'static str {
+    match std::env::consts::OS {
+        "macos" => "macosx",
+        "windows" => "windows",
+        "android" => "android",
+        "ios" => "iphoneos",
+        "freebsd" => "bsd",
+        _ => "linux",
+    }
+}
@@ -1,5 +1,7 @@
 mod build_disable_code;
 mod build_fix_code;
+mod build_xmake_refactor;
 
 pub use build_disable_code::*;
 pub use build_fix_code::*;
+pub use build_xmake_refactor::*;
@@ -1,19 +1,22 @@
 use std::str::FromStr;
 
 use lsp_types::{
-    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse, Diagnostic,
-    NumberOrString, Range, WorkspaceEdit,
+    ClientCapabilities, CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse,
+    Diagnostic, NumberOrString, Range, WorkspaceEdit,
 };
 use xmake_code_analysis::{DiagnosticCode, FileId, SemanticModel};
 
 use super::actions::{
     build_add_doc_tag, build_disable_file_changes, build_disable_next_line_changes,
+    build_fix_format, build_xmake_refactor_actions,
 };
 use crate::handlers::code_actions::actions::build_need_check_nil;
 
 pub fn build_actions(
     semantic_model: &SemanticModel,
     diagnostics: Vec<Diagnostic>,
+    range: Range,
+    client_capabilities: &ClientCapabilities,
 ) -> Option<CodeActionResponse> {
     let mut actions = Vec::new();
     let file_id = semantic_model.get_file_id();
@@ -50,6 +53,8 @@ pub fn build_actions(
         }
     }
 
+    build_xmake_refactor_actions(semantic_model, &mut actions, range, client_capabilities);
+
     if actions.is_empty() {
         return None;
     }
@@ -69,6 +74,7 @@ fn add_fix_code_action(
     match diagnostic_code {
         DiagnosticCode::NeedCheckNil => build_need_check_nil(semantic_model, actions, range, data),
         DiagnosticCode::UnknownDocTag => build_add_doc_tag(semantic_model, actions, range, data),
+        DiagnosticCode::CodeStyleCheck => build_fix_format(semantic_model, actions, range, data),
         _ => Some(()),
     }
 }
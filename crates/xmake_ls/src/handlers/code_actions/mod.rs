@@ -4,7 +4,7 @@ mod build_actions;
 use build_actions::build_actions;
 use lsp_types::{
     ClientCapabilities, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
-    Diagnostic, ServerCapabilities,
+    Diagnostic, Range, ServerCapabilities,
 };
 use tokio_util::sync::CancellationToken;
 use xmake_code_analysis::{FileId, XmakeAnalysis};
@@ -23,17 +23,25 @@ pub async fn on_code_action_handler(
     let diagnostics = params.context.diagnostics;
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
-    code_action(&analysis, file_id, diagnostics)
+    code_action(
+        &analysis,
+        file_id,
+        diagnostics,
+        params.range,
+        context.client_capabilities(),
+    )
 }
 
 pub fn code_action(
     analysis: &XmakeAnalysis,
     file_id: FileId,
     diagnostics: Vec<Diagnostic>,
+    range: Range,
+    client_capabilities: &ClientCapabilities,
 ) -> Option<CodeActionResponse> {
     let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
 
-    build_actions(&mut semantic_model, diagnostics)
+    build_actions(&mut semantic_model, diagnostics, range, client_capabilities)
 }
 
 pub struct CodeActionsCapabilities;
@@ -1,6 +1,8 @@
 use emmylua_parser::{LuaAst, LuaAstNode, LuaAstToken, LuaFuncStat, LuaLocalFuncStat, LuaVarExpr};
 use lsp_types::CodeLens;
-use xmake_code_analysis::{LuaDeclId, LuaMemberId, SemanticModel};
+use xmake_code_analysis::{LuaDeclId, LuaMemberId, SemanticModel, XmakeTargetKind};
+
+use crate::handlers::command::{TargetAction, make_run_task_command, make_target_action_command};
 
 use super::CodeLensData;
 
@@ -19,9 +21,56 @@ pub fn build_code_lens(semantic_model: &SemanticModel) -> Option<Vec<CodeLens>>
         }
     }
 
+    add_xmake_target_code_lens(semantic_model, &mut result);
+
     Some(result)
 }
 
+fn add_xmake_target_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+) -> Option<()> {
+    let file_id = semantic_model.get_file_id();
+    let targets = semantic_model
+        .get_db()
+        .get_xmake_index()
+        .get_targets(file_id)?;
+    let document = semantic_model.get_document();
+    let uri = document.get_uri();
+    for target in targets {
+        let Some(range) = document.to_lsp_range(target.name_range) else {
+            continue;
+        };
+        // xmake only runs the tasks with a menu from the command line
+        if target.kind == XmakeTargetKind::Task && target.properties.has_menu {
+            result.push(CodeLens {
+                range,
+                command: Some(make_run_task_command(&uri, &target.name)),
+                data: None,
+            });
+            continue;
+        }
+        if !target.kind.is_target() {
+            continue;
+        }
+
+        for action in [
+            TargetAction::Run,
+            TargetAction::Build,
+            TargetAction::Clean,
+            TargetAction::Rebuild,
+        ] {
+            result.push(CodeLens {
+                range,
+                command: Some(make_target_action_command(action, &uri, &target.name)),
+                data: None,
+            });
+        }
+    }
+
+    Some(())
+}
+
 fn add_func_stat_code_lens(
     semantic_model: &SemanticModel,
     result: &mut Vec<CodeLens>,
@@ -8,7 +8,7 @@ use lsp_types::{
 use resolve_code_lens::resolve_code_lens;
 use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
-use xmake_code_analysis::{LuaDeclId, LuaMemberId};
+use xmake_code_analysis::{FileId, LuaDeclId, LuaMemberId, XmakeAnalysis};
 
 use crate::context::ServerContextSnapshot;
 
@@ -21,7 +21,10 @@ pub async fn on_code_lens_handler(
 ) -> Option<Vec<CodeLens>> {
     let uri = params.text_document.uri;
     let analysis = context.analysis().read().await;
-    let file_id = analysis.get_file_id(&uri)?;
+    code_lens(&analysis, analysis.get_file_id(&uri)?)
+}
+
+pub fn code_lens(analysis: &XmakeAnalysis, file_id: FileId) -> Option<Vec<CodeLens>> {
     let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
 
     if !semantic_model.get_emmyrc().code_lens.enable {
@@ -1,6 +1,9 @@
+use std::{collections::HashMap, time::Duration};
+
+use lsp_types::{ApplyWorkspaceEditParams, Command, Range, TextEdit, Uri, WorkspaceEdit};
 use serde_json::Value;
 
-use crate::context::ServerContextSnapshot;
+use crate::{context::ServerContextSnapshot, util::time_cancel_token};
 
 use super::CommandSpec;
 
@@ -9,8 +12,48 @@ pub struct FixFormatCommand;
 impl CommandSpec for FixFormatCommand {
     const COMMAND: &str = "xmake.fix.format";
 
-    #[allow(unused)]
     async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        let uri: Uri = serde_json::from_value(args.first()?.clone()).ok()?;
+        let range: Range = serde_json::from_value(args.get(1)?.clone()).ok()?;
+        let new_text: String = serde_json::from_value(args.get(2)?.clone()).ok()?;
+
+        let apply_edit_params = ApplyWorkspaceEditParams {
+            label: None,
+            edit: WorkspaceEdit {
+                changes: Some(HashMap::from([(uri, vec![TextEdit { range, new_text }])])),
+                document_changes: None,
+                change_annotations: None,
+            },
+        };
+
+        let cancel_token = time_cancel_token(Duration::from_secs(5));
+        tokio::spawn(async move {
+            let res = context
+                .client()
+                .apply_edit(apply_edit_params, cancel_token)
+                .await;
+            if let Some(res) = res
+                && !res.applied
+            {
+                log::error!("Failed to apply edit: {:?}", res.failure_reason);
+            }
+        });
+
         Some(())
     }
 }
+
+/// Replaces `range` of the document with the formatted text reported by `CodeStyleCheck`
+pub fn make_fix_format_command(title: &str, uri: Uri, range: Range, new_text: &str) -> Command {
+    let args = vec![
+        serde_json::to_value(uri).unwrap(),
+        serde_json::to_value(range).unwrap(),
+        serde_json::to_value(new_text).unwrap(),
+    ];
+
+    Command {
+        title: title.to_string(),
+        command: FixFormatCommand::COMMAND.to_string(),
+        arguments: Some(args),
+    }
+}
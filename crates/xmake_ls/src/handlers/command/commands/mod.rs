@@ -5,18 +5,32 @@ use emmy_disable_code::DisableCodeCommand;
 use emmy_fix_format::FixFormatCommand;
 use serde_json::Value;
 use xmake_auto_require::AutoRequireCommand;
+use xmake_create_project::CreateProjectCommand;
+use xmake_dependency_graph::ShowDependencyGraphCommand;
+use xmake_run_task::RunTaskCommand;
+use xmake_target_action::{
+    BuildTargetCommand, CleanTargetCommand, RebuildTargetCommand, RunTargetCommand,
+};
 
 use crate::context::ServerContextSnapshot;
 
 mod emmy_add_doc_tag;
 mod emmy_disable_code;
 mod emmy_fix_format;
+mod user_input;
 mod xmake_auto_require;
+mod xmake_create_project;
+mod xmake_dependency_graph;
+mod xmake_run_task;
+mod xmake_target_action;
 
 pub use emmy_add_doc_tag::make_auto_doc_tag_command;
 #[allow(unused)]
 pub use emmy_disable_code::{DisableAction, make_disable_code_command};
+pub use emmy_fix_format::make_fix_format_command;
 pub use xmake_auto_require::make_auto_import;
+pub use xmake_run_task::make_run_task_command;
+pub use xmake_target_action::{TargetAction, make_target_action_command};
 
 pub trait CommandSpec {
     const COMMAND: &str;
@@ -30,6 +44,13 @@ static COMMANDS: LazyLock<Vec<String>> = LazyLock::new(|| {
         DisableCodeCommand::COMMAND.to_string(),
         FixFormatCommand::COMMAND.to_string(),
         AddDocTagCommand::COMMAND.to_string(),
+        RunTargetCommand::COMMAND.to_string(),
+        BuildTargetCommand::COMMAND.to_string(),
+        CleanTargetCommand::COMMAND.to_string(),
+        RebuildTargetCommand::COMMAND.to_string(),
+        ShowDependencyGraphCommand::COMMAND.to_string(),
+        CreateProjectCommand::COMMAND.to_string(),
+        RunTaskCommand::COMMAND.to_string(),
     ]
 });
 
@@ -47,6 +68,15 @@ pub async fn dispatch_command(
         DisableCodeCommand::COMMAND => DisableCodeCommand::handle(context, args).await,
         FixFormatCommand::COMMAND => FixFormatCommand::handle(context, args).await,
         AddDocTagCommand::COMMAND => AddDocTagCommand::handle(context, args).await,
+        RunTargetCommand::COMMAND => RunTargetCommand::handle(context, args).await,
+        BuildTargetCommand::COMMAND => BuildTargetCommand::handle(context, args).await,
+        CleanTargetCommand::COMMAND => CleanTargetCommand::handle(context, args).await,
+        RebuildTargetCommand::COMMAND => RebuildTargetCommand::handle(context, args).await,
+        ShowDependencyGraphCommand::COMMAND => {
+            ShowDependencyGraphCommand::handle(context, args).await
+        }
+        CreateProjectCommand::COMMAND => CreateProjectCommand::handle(context, args).await,
+        RunTaskCommand::COMMAND => RunTaskCommand::handle(context, args).await,
         _ => Some(()),
     }
 }
@@ -0,0 +1,40 @@
+use lsp_types::{MessageActionItem, MessageType, ShowMessageParams, ShowMessageRequestParams};
+use tokio_util::sync::CancellationToken;
+
+use crate::context::{ClientProxy, ShowInputBoxParams};
+
+/// Ask the user to pick one of `items`, `None` when the question is dismissed
+pub(super) async fn choose(client: &ClientProxy, message: &str, items: &[&str]) -> Option<String> {
+    let params = ShowMessageRequestParams {
+        typ: MessageType::INFO,
+        message: message.to_string(),
+        actions: Some(
+            items
+                .iter()
+                .map(|item| MessageActionItem {
+                    title: item.to_string(),
+                    properties: Default::default(),
+                })
+                .collect(),
+        ),
+    };
+    let item = client
+        .show_message_request(params, CancellationToken::new())
+        .await?;
+    Some(item.title)
+}
+
+/// Ask the user for a line of text with `xmake/showInputBox`, `None` when the input is dismissed
+/// or the client can't show one
+pub(super) async fn input(client: &ClientProxy, params: ShowInputBoxParams) -> Option<String> {
+    client
+        .show_input_box(params, CancellationToken::new())
+        .await
+}
+
+pub(super) fn show_error(client: &ClientProxy, message: &str) {
+    client.show_message(ShowMessageParams {
+        typ: MessageType::ERROR,
+        message: message.to_string(),
+    });
+}
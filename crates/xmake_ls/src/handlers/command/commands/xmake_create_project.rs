@@ -0,0 +1,197 @@
+use std::path::PathBuf;
+
+use lsp_types::{MessageType, ShowMessageParams};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use xmake_wrapper::{XmakeTemplate, get_template_languages};
+
+use crate::{
+    context::{ServerContextSnapshot, ShowInputBoxParams},
+    handlers::initialized::collect_files,
+};
+
+use super::{
+    CommandSpec,
+    user_input::{choose, input, show_error},
+};
+
+/// Arguments of `xmake.createProject`, the directory, language and template are asked for when
+/// missing
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectParams {
+    /// Directory of the new project, created if it does not exist
+    pub directory: Option<PathBuf>,
+    /// e.g. `c++`
+    pub language: Option<String>,
+    /// e.g. `console`, `static`, `qt.widgetapp`
+    pub template: Option<String>,
+}
+
+pub struct CreateProjectCommand;
+
+impl CommandSpec for CreateProjectCommand {
+    const COMMAND: &str = "xmake.createProject";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        let params: CreateProjectParams = match args.first() {
+            Some(arg) => serde_json::from_value(arg.clone()).ok()?,
+            None => CreateProjectParams::default(),
+        };
+
+        // waits for the user to pick a template, don't hold the execute command request
+        tokio::spawn(async move {
+            create_project(context, params).await;
+        });
+
+        Some(())
+    }
+}
+
+async fn create_project(context: ServerContextSnapshot, params: CreateProjectParams) -> Option<()> {
+    let client = context.client();
+    let directory = match params.directory {
+        Some(directory) => directory,
+        None => ask_directory(&context).await?,
+    };
+    let is_empty = match tokio::fs::read_dir(&directory).await {
+        Ok(mut entries) => matches!(entries.next_entry().await, Ok(None)),
+        Err(_) => !tokio::fs::try_exists(&directory).await.unwrap_or(true),
+    };
+    if !is_empty {
+        show_error(
+            client,
+            &format!("{} already exists and is not empty", directory.display()),
+        );
+        return None;
+    }
+
+    let templates = context.xmake().get_templates().await;
+    if templates.is_empty() {
+        show_error(client, "No xmake project templates found");
+        return None;
+    }
+
+    let language = match params.language {
+        Some(language) => language,
+        None => {
+            let languages = get_template_languages(&templates);
+            choose(client, "Language of the new xmake project", &languages).await?
+        }
+    };
+    let language_templates = templates
+        .iter()
+        .filter(|template| template.language == language)
+        .map(|template| template.name.as_str())
+        .collect::<Vec<_>>();
+    let template = match params.template {
+        Some(template) => template,
+        None => {
+            choose(
+                client,
+                &format!("Template of the new {} project", language),
+                &language_templates,
+            )
+            .await?
+        }
+    };
+    if !templates.contains(&XmakeTemplate {
+        language: language.clone(),
+        name: template.clone(),
+    }) {
+        show_error(
+            client,
+            &format!(
+                "Unknown xmake template {} for {}, available: {}",
+                template,
+                language,
+                language_templates.join(", ")
+            ),
+        );
+        return None;
+    }
+
+    if let Err(err) = tokio::fs::create_dir_all(&directory).await {
+        show_error(
+            client,
+            &format!("Failed to create {}: {}", directory.display(), err),
+        );
+        return None;
+    }
+    let result = context
+        .xmake()
+        .clone()
+        .with_working_dir(directory.clone())
+        .create()
+        .arg("-l")
+        .arg(language.as_str())
+        .arg("-t")
+        .arg(template.as_str())
+        .execute()
+        .await;
+    if let Err(err) = result {
+        show_error(client, &format!("xmake create failed: {}", err));
+        return None;
+    }
+
+    // walk and read the files outside of the analysis lock, requests keep being answered
+    let emmyrc = context.analysis().read().await.get_emmyrc();
+    let workspace = vec![directory.clone()];
+    let files = tokio::task::spawn_blocking(move || {
+        collect_files(&workspace, &emmyrc)
+            .into_iter()
+            .map(|file| file.into_tuple())
+            .collect::<Vec<_>>()
+    })
+    .await
+    .ok()?;
+    {
+        let mut analysis = context.analysis().write().await;
+        analysis.add_main_workspace(directory.clone());
+        analysis.update_files_by_path(files);
+    }
+    context
+        .workspace_manager()
+        .write()
+        .await
+        .workspace_folders
+        .push(directory.clone());
+
+    client.show_message(ShowMessageParams {
+        typ: MessageType::INFO,
+        message: format!(
+            "Created {} {} project in {}",
+            language,
+            template,
+            directory.display()
+        ),
+    });
+    Some(())
+}
+
+/// Ask for the directory of the new project, the first workspace folder is the default
+async fn ask_directory(context: &ServerContextSnapshot) -> Option<PathBuf> {
+    let workspace_folder = context
+        .workspace_manager()
+        .read()
+        .await
+        .workspace_folders
+        .first()
+        .cloned();
+    let params = ShowInputBoxParams {
+        prompt: "Directory of the new xmake project".to_string(),
+        placeholder: Some("/path/to/project".to_string()),
+        value: workspace_folder.map(|folder| folder.to_string_lossy().to_string()),
+    };
+    let directory = input(context.client(), params).await;
+    match directory.filter(|directory| !directory.trim().is_empty()) {
+        Some(directory) => Some(PathBuf::from(directory.trim())),
+        None => {
+            show_error(
+                context.client(),
+                "xmake.createProject needs the directory of the new project",
+            );
+            None
+        }
+    }
+}
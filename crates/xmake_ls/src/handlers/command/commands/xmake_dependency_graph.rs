@@ -0,0 +1,40 @@
+use lsp_types::{LogMessageParams, MessageType, ShowMessageParams, Uri};
+use serde_json::Value;
+
+use crate::{context::ServerContextSnapshot, handlers::dependency_graph::build_dependency_graph};
+
+use super::CommandSpec;
+
+/// Write the dependency graph as Graphviz DOT to the output, clients wanting the data use the
+/// `xmake/dependencyGraph` request
+pub struct ShowDependencyGraphCommand;
+
+impl CommandSpec for ShowDependencyGraphCommand {
+    const COMMAND: &str = "xmake.showDependencyGraph";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        let uri: Option<Uri> = args
+            .first()
+            .and_then(|arg| serde_json::from_value(arg.clone()).ok());
+        let result = {
+            let analysis = context.analysis().read().await;
+            build_dependency_graph(&analysis, uri.as_ref())?
+        };
+
+        let client = context.client();
+        client.log_message(LogMessageParams {
+            typ: MessageType::INFO,
+            message: result.dot,
+        });
+        client.show_message(ShowMessageParams {
+            typ: MessageType::INFO,
+            message: format!(
+                "xmake dependency graph: {} nodes, {} edges, see the output for the DOT graph",
+                result.graph.nodes.len(),
+                result.graph.edges.len()
+            ),
+        });
+
+        Some(())
+    }
+}
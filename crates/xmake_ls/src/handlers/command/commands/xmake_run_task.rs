@@ -0,0 +1,303 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use lsp_types::{Command, LogMessageParams, MessageType, ShowMessageParams, Uri};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use xmake_code_analysis::{XmakeMenuOption, XmakeTargetKind, uri_to_file_path};
+use xmake_wrapper::{ExecuteOptions, OutputLine, XmakeError};
+
+use crate::context::{ProgressTask, ServerContextSnapshot, ShowInputBoxParams};
+
+use super::{
+    CommandSpec,
+    user_input::{choose, input, show_error},
+};
+
+const YES: &str = "Yes";
+const NO: &str = "No";
+
+/// Arguments of `xmake.runTask`, the flags of the menu are asked for when `options` is missing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunTaskParams {
+    /// File declaring the task
+    pub uri: Uri,
+    pub task: String,
+    /// Values of the menu options by long name, `true` for flags, a string for `kv` and `v`
+    /// options and a list of strings for `vs` options
+    pub options: Option<HashMap<String, Value>>,
+}
+
+pub struct RunTaskCommand;
+
+impl CommandSpec for RunTaskCommand {
+    const COMMAND: &str = "xmake.runTask";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        let params: RunTaskParams = serde_json::from_value(args.first()?.clone()).ok()?;
+        let file_path = uri_to_file_path(&params.uri)?;
+        let project_dir = context
+            .workspace_manager()
+            .read()
+            .await
+            .get_project_dir(&file_path)?;
+        let menu_options = {
+            let analysis = context.analysis().read().await;
+            let file_id = analysis.get_file_id(&params.uri)?;
+            analysis
+                .compilation
+                .get_db()
+                .get_xmake_index()
+                .get_targets(file_id)?
+                .iter()
+                .find(|target| {
+                    target.kind == XmakeTargetKind::Task
+                        && target.name == params.task
+                        && target.properties.has_menu
+                })
+                .map(|task| task.properties.menu_options.clone())
+        };
+        let Some(menu_options) = menu_options else {
+            show_error(
+                context.client(),
+                &format!(
+                    "No task {} with a menu in {}",
+                    params.task,
+                    file_path.display()
+                ),
+            );
+            return None;
+        };
+
+        // waits for the user to answer and for the task to finish, don't hold the request
+        tokio::spawn(async move {
+            run_task(context, project_dir, params, menu_options).await;
+        });
+
+        Some(())
+    }
+}
+
+pub fn make_run_task_command(uri: &Uri, task_name: &str) -> Command {
+    let params = RunTaskParams {
+        uri: uri.clone(),
+        task: task_name.to_string(),
+        options: None,
+    };
+
+    Command {
+        title: "▶ Run task".to_string(),
+        command: RunTaskCommand::COMMAND.to_string(),
+        arguments: Some(vec![serde_json::to_value(params).unwrap()]),
+    }
+}
+
+async fn run_task(
+    context: ServerContextSnapshot,
+    project_dir: PathBuf,
+    params: RunTaskParams,
+    menu_options: Vec<XmakeMenuOption>,
+) -> Option<()> {
+    let client = context.client();
+    let task_name = params.task;
+    let option_values = match params.options {
+        Some(options) => options,
+        None => ask_flags(&context, &task_name, &menu_options).await?,
+    };
+    let args = menu_options
+        .iter()
+        .filter_map(|option| {
+            let value = option_values.get(&option.name.value)?;
+            Some(get_option_args(option, value))
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let status_bar = context.status_bar();
+    let progress = status_bar
+        .create_cancellable_progress_task(ProgressTask::XmakeTask)
+        .await;
+    status_bar.update_progress_run(&progress, None, Some(format!("Running {}", task_name)));
+
+    let xmake = context.xmake().clone().with_working_dir(project_dir);
+    let options = ExecuteOptions::new().cancel_token(progress.get_cancel_token());
+    let result = xmake
+        .custom(task_name.as_str())
+        .args(args)
+        .execute_streaming(options, |line| {
+            let (typ, message) = match line {
+                OutputLine::Stdout(text) => (MessageType::INFO, text),
+                OutputLine::Stderr(text) => (MessageType::ERROR, text),
+            };
+            client.log_message(LogMessageParams { typ, message });
+        })
+        .await;
+
+    let outcome = match &result {
+        Ok(_) => "finished",
+        Err(XmakeError::Cancelled { .. }) => "cancelled",
+        Err(_) => "failed",
+    };
+    status_bar.finish_progress_run(&progress, Some(format!("xmake {} {}", task_name, outcome)));
+
+    match result {
+        Ok(_) | Err(XmakeError::Cancelled { .. }) => {}
+        Err(err) => {
+            client.show_message(ShowMessageParams {
+                typ: MessageType::ERROR,
+                message: format!("xmake {} failed: {}", task_name, err),
+            });
+        }
+    }
+
+    Some(())
+}
+
+/// Ask whether to pass each flag of the menu and for the value of the other options, an empty
+/// value leaves the option out. `None` when the user dismisses a question
+async fn ask_flags(
+    context: &ServerContextSnapshot,
+    task_name: &str,
+    menu_options: &[XmakeMenuOption],
+) -> Option<HashMap<String, Value>> {
+    let client = context.client();
+    let mut values = HashMap::new();
+    for option in menu_options {
+        let usage = option.get_usage();
+        let description = option
+            .description
+            .as_ref()
+            .map(|description| format!(" ({})", description))
+            .unwrap_or_default();
+        let value = if option.kind == "k" {
+            let message = format!("xmake {}: pass {}?{}", task_name, usage, description);
+            if choose(client, &message, &[YES, NO]).await? != YES {
+                continue;
+            }
+            Value::Bool(true)
+        } else {
+            let params = ShowInputBoxParams {
+                prompt: format!("xmake {}: {}{}", task_name, usage, description),
+                placeholder: Some("Leave empty to skip".to_string()),
+                value: None,
+            };
+            let Some(text) = input(client, params).await else {
+                show_error(
+                    client,
+                    &format!(
+                        "xmake.runTask needs a value for {}, pass the options to run {} without asking",
+                        usage, task_name
+                    ),
+                );
+                return None;
+            };
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            match option.kind.as_str() {
+                "vs" => Value::Array(
+                    text.split_whitespace()
+                        .map(|value| Value::String(value.to_string()))
+                        .collect(),
+                ),
+                _ => Value::String(text.to_string()),
+            }
+        };
+        values.insert(option.name.value.clone(), value);
+    }
+
+    Some(values)
+}
+
+/// The command line arguments passing `value` to `option`
+fn get_option_args(option: &XmakeMenuOption, value: &Value) -> Vec<String> {
+    let name = &option.name.value;
+    match (option.kind.as_str(), &option.short) {
+        ("k", Some(short)) if value.as_bool() == Some(true) => vec![format!("-{}", short)],
+        ("k", None) if value.as_bool() == Some(true) => vec![format!("--{}", name)],
+        ("k", _) => vec![],
+        ("kv", Some(short)) => get_string_values(value)
+            .into_iter()
+            .take(1)
+            .flat_map(|value| [format!("-{}", short), value])
+            .collect(),
+        ("kv", None) => get_string_values(value)
+            .into_iter()
+            .take(1)
+            .map(|value| format!("--{}={}", name, value))
+            .collect(),
+        _ => get_string_values(value),
+    }
+}
+
+fn get_string_values(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(value) => vec![value.clone()],
+        Value::Number(value) => vec![value.to_string()],
+        Value::Array(values) => values.iter().flat_map(get_string_values).collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rowan::TextRange;
+    use serde_json::json;
+    use xmake_code_analysis::XmakeTargetValue;
+
+    use super::*;
+
+    fn menu_option(short: Option<&str>, name: &str, kind: &str) -> XmakeMenuOption {
+        XmakeMenuOption {
+            short: short.map(|short| short.to_string()),
+            name: XmakeTargetValue {
+                value: name.to_string(),
+                range: TextRange::default(),
+            },
+            kind: kind.to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_flag_args() {
+        let short = menu_option(Some("f"), "force", "k");
+        let long = menu_option(None, "force", "k");
+        assert_eq!(get_option_args(&short, &json!(true)), vec!["-f"]);
+        assert_eq!(get_option_args(&long, &json!(true)), vec!["--force"]);
+        assert!(get_option_args(&short, &json!(false)).is_empty());
+    }
+
+    #[test]
+    fn test_key_value_args() {
+        let short = menu_option(Some("m"), "mode", "kv");
+        let long = menu_option(None, "mode", "kv");
+        assert_eq!(
+            get_option_args(&short, &json!("release")),
+            vec!["-m", "release"]
+        );
+        assert_eq!(
+            get_option_args(&long, &json!("release")),
+            vec!["--mode=release"]
+        );
+        assert_eq!(get_option_args(&long, &json!(2)), vec!["--mode=2"]);
+        assert_eq!(
+            get_option_args(&long, &json!(["release", "debug"])),
+            vec!["--mode=release"]
+        );
+        assert!(get_option_args(&long, &json!(null)).is_empty());
+    }
+
+    #[test]
+    fn test_value_args() {
+        let value = menu_option(None, "target", "v");
+        let values = menu_option(None, "files", "vs");
+        assert_eq!(get_option_args(&value, &json!("app")), vec!["app"]);
+        assert_eq!(
+            get_option_args(&values, &json!(["a.c", "b.c"])),
+            vec!["a.c", "b.c"]
+        );
+        assert_eq!(get_option_args(&values, &json!("a.c")), vec!["a.c"]);
+    }
+}
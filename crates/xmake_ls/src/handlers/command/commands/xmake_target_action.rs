@@ -0,0 +1,362 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use lsp_types::{
+    Command, Diagnostic, DiagnosticSeverity, LogMessageParams, MessageType, NumberOrString,
+    Position, Range, ShowMessageParams, Uri,
+};
+use serde_json::Value;
+use xmake_code_analysis::{file_path_to_uri, uri_to_file_path};
+use xmake_wrapper::{
+    BuildDiagnostic, BuildDiagnosticSeverity, ExecuteOptions, OutputLine, XmakeCommandBuilder,
+    XmakeError, XmakeWrapper, parse_build_diagnostics,
+};
+
+use crate::context::{ProgressTask, ServerContextSnapshot};
+
+use super::CommandSpec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetAction {
+    Run,
+    Build,
+    Clean,
+    Rebuild,
+}
+
+impl TargetAction {
+    pub fn get_title(&self) -> &'static str {
+        match self {
+            TargetAction::Run => "▶ Run",
+            TargetAction::Build => "Build",
+            TargetAction::Clean => "Clean",
+            TargetAction::Rebuild => "Rebuild",
+        }
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            TargetAction::Run => "run",
+            TargetAction::Build => "build",
+            TargetAction::Clean => "clean",
+            TargetAction::Rebuild => "rebuild",
+        }
+    }
+
+    pub fn get_command_name(&self) -> &'static str {
+        match self {
+            TargetAction::Run => RunTargetCommand::COMMAND,
+            TargetAction::Build => BuildTargetCommand::COMMAND,
+            TargetAction::Clean => CleanTargetCommand::COMMAND,
+            TargetAction::Rebuild => RebuildTargetCommand::COMMAND,
+        }
+    }
+
+    fn get_progress_name(&self) -> &'static str {
+        match self {
+            TargetAction::Run => "Running",
+            TargetAction::Build => "Building",
+            TargetAction::Clean => "Cleaning",
+            TargetAction::Rebuild => "Rebuilding",
+        }
+    }
+
+    /// Whether the output is only the build output, the output of a run also has what the
+    /// program prints, which must not be parsed as compiler diagnostics
+    fn is_build(&self) -> bool {
+        matches!(self, TargetAction::Build | TargetAction::Rebuild)
+    }
+
+    fn make_command(&self, xmake: &XmakeWrapper, target_name: &str) -> XmakeCommandBuilder {
+        match self {
+            TargetAction::Run => xmake.run().arg(target_name),
+            TargetAction::Build => xmake.build().arg(target_name),
+            TargetAction::Clean => xmake.clean().arg(target_name),
+            TargetAction::Rebuild => xmake.build().arg("-r").arg(target_name),
+        }
+    }
+}
+
+pub struct RunTargetCommand;
+
+impl CommandSpec for RunTargetCommand {
+    const COMMAND: &str = "xmake.runTarget";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        spawn_target_action(context, args, TargetAction::Run).await
+    }
+}
+
+pub struct BuildTargetCommand;
+
+impl CommandSpec for BuildTargetCommand {
+    const COMMAND: &str = "xmake.buildTarget";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        spawn_target_action(context, args, TargetAction::Build).await
+    }
+}
+
+pub struct CleanTargetCommand;
+
+impl CommandSpec for CleanTargetCommand {
+    const COMMAND: &str = "xmake.cleanTarget";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        spawn_target_action(context, args, TargetAction::Clean).await
+    }
+}
+
+pub struct RebuildTargetCommand;
+
+impl CommandSpec for RebuildTargetCommand {
+    const COMMAND: &str = "xmake.rebuildTarget";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+        spawn_target_action(context, args, TargetAction::Rebuild).await
+    }
+}
+
+pub fn make_target_action_command(action: TargetAction, uri: &Uri, target_name: &str) -> Command {
+    let args = vec![
+        serde_json::to_value(uri).unwrap(),
+        serde_json::to_value(target_name).unwrap(),
+    ];
+
+    Command {
+        title: action.get_title().to_string(),
+        command: action.get_command_name().to_string(),
+        arguments: Some(args),
+    }
+}
+
+async fn spawn_target_action(
+    context: ServerContextSnapshot,
+    args: Vec<Value>,
+    action: TargetAction,
+) -> Option<()> {
+    let uri: Uri = serde_json::from_value(args.first()?.clone()).ok()?;
+    let target_name: String = serde_json::from_value(args.get(1)?.clone()).ok()?;
+    let file_path = uri_to_file_path(&uri)?;
+    let project_dir = context
+        .workspace_manager()
+        .read()
+        .await
+        .get_project_dir(&file_path)?;
+
+    // builds can take a long time, don't hold the execute command request
+    tokio::spawn(async move {
+        run_target_action(context, project_dir, target_name, action).await;
+    });
+
+    Some(())
+}
+
+async fn run_target_action(
+    context: ServerContextSnapshot,
+    project_dir: PathBuf,
+    target_name: String,
+    action: TargetAction,
+) {
+    let status_bar = context.status_bar();
+    let progress = status_bar
+        .create_cancellable_progress_task(ProgressTask::XmakeBuild)
+        .await;
+    status_bar.update_progress_run(
+        &progress,
+        None,
+        Some(format!("{} {}", action.get_progress_name(), target_name)),
+    );
+
+    let xmake = context
+        .xmake()
+        .clone()
+        .with_working_dir(project_dir.clone());
+    let client = context.client();
+    let options = ExecuteOptions::new().cancel_token(progress.get_cancel_token());
+    let result = action
+        .make_command(&xmake, &target_name)
+        .execute_streaming(options, |line| match line {
+            OutputLine::Stdout(text) => {
+                if let Some(percentage) = parse_build_percentage(&text) {
+                    status_bar.update_progress_run(&progress, Some(percentage), Some(text.clone()));
+                }
+                client.log_message(LogMessageParams {
+                    typ: MessageType::INFO,
+                    message: text,
+                });
+            }
+            OutputLine::Stderr(text) => {
+                client.log_message(LogMessageParams {
+                    typ: MessageType::ERROR,
+                    message: text,
+                });
+            }
+        })
+        .await;
+
+    if action.is_build() {
+        let output = match &result {
+            Ok(output) => Some(format!("{}\n{}", output.stdout, output.stderr)),
+            Err(XmakeError::ExecutionFailed { stdout, stderr, .. }) => {
+                Some(format!("{}\n{}", stdout, stderr))
+            }
+            Err(_) => None,
+        };
+        // a successful build clears the errors of the previous one
+        if let Some(output) = output {
+            let diagnostics = to_lsp_diagnostics(&project_dir, parse_build_diagnostics(&output));
+            context
+                .file_diagnostic()
+                .set_build_diagnostics(diagnostics)
+                .await;
+        }
+    }
+
+    let outcome = match &result {
+        Ok(_) => "finished",
+        Err(XmakeError::Cancelled { .. }) => "cancelled",
+        Err(_) => "failed",
+    };
+    status_bar.finish_progress_run(
+        &progress,
+        Some(format!(
+            "xmake {} {} {}",
+            action.get_name(),
+            target_name,
+            outcome
+        )),
+    );
+
+    match result {
+        Ok(_) | Err(XmakeError::Cancelled { .. }) => {}
+        Err(err) => {
+            client.show_message(ShowMessageParams {
+                typ: MessageType::ERROR,
+                message: format!(
+                    "xmake {} {} failed: {}",
+                    action.get_name(),
+                    target_name,
+                    err
+                ),
+            });
+        }
+    }
+}
+
+fn to_lsp_diagnostics(
+    project_dir: &Path,
+    build_diagnostics: Vec<BuildDiagnostic>,
+) -> HashMap<String, Vec<Diagnostic>> {
+    let mut diagnostics: HashMap<String, Vec<Diagnostic>> = HashMap::new();
+    for build_diagnostic in build_diagnostics {
+        let Some(uri) = file_path_to_uri(&project_dir.join(&build_diagnostic.file)) else {
+            continue;
+        };
+
+        let line = build_diagnostic.line.saturating_sub(1);
+        let range = match build_diagnostic.column {
+            Some(column) => {
+                let position = Position::new(line, column.saturating_sub(1));
+                Range::new(position, position)
+            }
+            // the client clamps the end to the line length
+            None => Range::new(Position::new(line, 0), Position::new(line, u32::MAX)),
+        };
+        let severity = match build_diagnostic.severity {
+            BuildDiagnosticSeverity::Error => DiagnosticSeverity::ERROR,
+            BuildDiagnosticSeverity::Warning => DiagnosticSeverity::WARNING,
+            BuildDiagnosticSeverity::Note => DiagnosticSeverity::INFORMATION,
+        };
+
+        diagnostics
+            .entry(uri.as_str().to_string())
+            .or_default()
+            .push(Diagnostic {
+                range,
+                severity: Some(severity),
+                code: build_diagnostic.code.map(NumberOrString::String),
+                source: Some("xmake".to_string()),
+                message: build_diagnostic.message,
+                ..Default::default()
+            });
+    }
+
+    diagnostics
+}
+
+/// Parse the progress of xmake build output like `[ 42%]: compiling.release src/main.cpp`
+fn parse_build_percentage(line: &str) -> Option<u32> {
+    let end = line.find("%]")?;
+    let start = line[..end].rfind('[')? + 1;
+    line[start..end].trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_diagnostic(file: &str, line: u32, column: Option<u32>) -> BuildDiagnostic {
+        BuildDiagnostic {
+            file: file.to_string(),
+            line,
+            column,
+            severity: BuildDiagnosticSeverity::Warning,
+            code: Some("C4101".to_string()),
+            message: "unused variable".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_to_lsp_diagnostics() {
+        let project_dir = std::env::temp_dir().join("project");
+        let header = std::env::temp_dir().join("include").join("lib.h");
+        let diagnostics = to_lsp_diagnostics(
+            &project_dir,
+            vec![
+                build_diagnostic("src/main.cpp", 3, Some(5)),
+                build_diagnostic("src/main.cpp", 1, None),
+                build_diagnostic(&header.to_string_lossy(), 0, None),
+            ],
+        );
+
+        let main_uri = file_path_to_uri(&project_dir.join("src/main.cpp")).unwrap();
+        let main_diagnostics = &diagnostics[main_uri.as_str()];
+        assert_eq!(main_diagnostics.len(), 2);
+        assert_eq!(
+            main_diagnostics[0].range,
+            Range::new(Position::new(2, 4), Position::new(2, 4))
+        );
+        assert_eq!(
+            main_diagnostics[0].severity,
+            Some(DiagnosticSeverity::WARNING)
+        );
+        assert_eq!(
+            main_diagnostics[0].code,
+            Some(NumberOrString::String("C4101".to_string()))
+        );
+        assert_eq!(main_diagnostics[0].source.as_deref(), Some("xmake"));
+        // no column marks the whole line
+        assert_eq!(
+            main_diagnostics[1].range,
+            Range::new(Position::new(0, 0), Position::new(0, u32::MAX))
+        );
+
+        // absolute paths are kept, a missing line is clamped
+        let header_uri = file_path_to_uri(&header).unwrap();
+        assert_eq!(
+            diagnostics[header_uri.as_str()][0].range.start,
+            Position::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn test_is_build() {
+        assert!(TargetAction::Build.is_build());
+        assert!(TargetAction::Rebuild.is_build());
+        assert!(!TargetAction::Run.is_build());
+        assert!(!TargetAction::Clean.is_build());
+    }
+}
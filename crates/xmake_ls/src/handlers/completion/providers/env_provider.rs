@@ -6,7 +6,7 @@ use emmylua_parser::{
 use lsp_types::{CompletionItem, CompletionItemKind, CompletionTriggerKind};
 use rowan::TextSize;
 use xmake_code_analysis::{
-    DbIndex, FileId, LuaSemanticDeclId, LuaSignatureId, LuaType, XmakeScope,
+    DbIndex, FileId, LuaSemanticDeclId, LuaSignatureId, LuaType, XmakeScope, get_xmake_api_since,
 };
 
 use crate::handlers::completion::{
@@ -202,6 +202,18 @@ pub fn add_global_env(
             continue;
         }
 
+        // hide apis the installed xmake or the project's `set_xmakever` doesn't support
+        if let Some(since) = get_xmake_api_since(builder.semantic_model.get_db(), &typ) {
+            let db = builder.semantic_model.get_db();
+            if db
+                .get_xmake_index()
+                .check_api_version(file_id, &since)
+                .is_some()
+            {
+                continue;
+            }
+        }
+
         if duplicated_name.contains(&name) {
             continue;
         }
@@ -243,6 +255,7 @@ fn filter_func_by_scope(
                 (XmakeScope::Rule, x) if !x.is_rule() => return Some(()),
                 (XmakeScope::Target, x) if !x.is_target() => return Some(()),
                 (XmakeScope::Task, x) if !x.is_task() => return Some(()),
+                (XmakeScope::Toolchain, x) if !x.is_toolchain() => return Some(()),
                 _ => {}
             }
         }
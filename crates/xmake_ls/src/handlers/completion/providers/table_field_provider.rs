@@ -3,7 +3,10 @@ use std::collections::HashSet;
 use emmylua_parser::{LuaAst, LuaAstNode, LuaKind, LuaTableExpr, LuaTableField, LuaTokenKind};
 use lsp_types::{CompletionItem, InsertTextFormat, InsertTextMode};
 use rowan::NodeOrToken;
-use xmake_code_analysis::{InferGuard, LuaMemberInfo, LuaMemberKey, LuaType, get_real_type};
+use xmake_code_analysis::{
+    InferGuard, LuaMemberInfo, LuaMemberKey, LuaSemanticDeclId, LuaType, get_real_type,
+    get_target_attr_apis, get_target_attr_call,
+};
 
 use crate::handlers::completion::{
     add_completions::{check_visibility, is_deprecated},
@@ -40,11 +43,6 @@ fn add_table_field_key_completion(builder: &mut CompletionBuilder) -> Option<()>
         _ => None,
     }?;
 
-    let table_type = builder
-        .semantic_model
-        .infer_table_should_be(table_expr.clone())?;
-    let member_infos = builder.semantic_model.get_member_infos(&table_type)?;
-
     let mut duplicated_set = HashSet::new();
     for field in table_expr.get_fields() {
         let key = field.get_field_key();
@@ -53,6 +51,17 @@ fn add_table_field_key_completion(builder: &mut CompletionBuilder) -> Option<()>
         }
     }
 
+    if get_target_attr_call(&table_expr).is_some() {
+        add_target_attr_completion(builder, &duplicated_set);
+        builder.stop_here();
+        return Some(());
+    }
+
+    let table_type = builder
+        .semantic_model
+        .infer_table_should_be(table_expr.clone())?;
+    let member_infos = builder.semantic_model.get_member_infos(&table_type)?;
+
     for member_info in member_infos {
         if duplicated_set.contains(&member_info.key.to_path()) {
             continue;
@@ -156,6 +165,32 @@ fn add_field_key_completion(
     Some(())
 }
 
+/// Fields of `target("app", {...})`, one per api of the target scope
+fn add_target_attr_completion(
+    builder: &mut CompletionBuilder,
+    duplicated_set: &HashSet<String>,
+) -> Option<()> {
+    let apis = get_target_attr_apis(builder.semantic_model.get_db());
+    let mut fields = apis
+        .into_iter()
+        .filter(|(field, _)| !duplicated_set.contains(field))
+        .collect::<Vec<_>>();
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (field, signature_id) in fields {
+        let id = LuaSemanticDeclId::Signature(signature_id);
+        let completion_item = CompletionItem {
+            label: format!("{} = ", field),
+            kind: Some(lsp_types::CompletionItemKind::PROPERTY),
+            data: CompletionData::from_property_owner_id(builder, id.clone(), None),
+            deprecated: Some(is_deprecated(builder, id)),
+            insert_text: Some(format!("{} = ", field)),
+            ..Default::default()
+        };
+        builder.add_completion_item(completion_item);
+    }
+    Some(())
+}
+
 /// 是否在当前文件的 env 中, 将会排除掉`std`
 fn in_env(builder: &mut CompletionBuilder, target_name: &str, target_type: &LuaType) -> Option<()> {
     let file_id = builder.semantic_model.get_file_id();
@@ -2,11 +2,14 @@ mod deps_provider;
 mod file_path_provider;
 mod import_module_provider;
 mod include_module_provider;
+mod task_option_provider;
+mod toolchain_provider;
 
-use emmylua_parser::{
-    LuaAstNode, LuaAstToken, LuaCallArgList, LuaCallExpr, LuaLiteralExpr, LuaStringToken,
+use emmylua_parser::{LuaAstToken, LuaStringToken};
+use xmake_code_analysis::{
+    XmakeFunction, get_option_get_call, get_string_api, get_toolchain_arg,
+    get_xmake_function_by_name,
 };
-use xmake_code_analysis::{XmakeFunction, get_xmake_function};
 
 use crate::handlers::completion::completion_builder::CompletionBuilder;
 
@@ -16,11 +19,21 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
     }
 
     let string_token = LuaStringToken::cast(builder.trigger_token.clone())?;
-    let call_expr = string_token
-        .get_parent::<LuaLiteralExpr>()?
-        .get_parent::<LuaCallArgList>()?
-        .get_parent::<LuaCallExpr>()?;
-    let xmake_function = get_xmake_function(&call_expr)?;
+    if let Some(call_expr) = get_option_get_call(&string_token) {
+        task_option_provider::add_completion(builder, string_token, call_expr)?;
+        builder.stop_here();
+        return Some(());
+    }
+
+    // `add_deps("core")` and `target("app", {deps = "core"})` complete the same
+    let (api, _) = get_string_api(&string_token)?;
+    if get_toolchain_arg(&string_token).is_some() {
+        toolchain_provider::add_completion(builder, string_token);
+        builder.stop_here();
+        return Some(());
+    }
+
+    let xmake_function = get_xmake_function_by_name(&api)?;
     match xmake_function {
         XmakeFunction::AddFiles => {
             file_path_provider::add_completion(builder, string_token);
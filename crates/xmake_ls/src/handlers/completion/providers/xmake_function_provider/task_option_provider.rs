@@ -0,0 +1,51 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaStringToken};
+use lsp_types::{CompletionItem, CompletionItemLabelDetails, CompletionTextEdit, TextEdit};
+use xmake_code_analysis::find_running_task;
+
+use crate::handlers::completion::{
+    completion_builder::CompletionBuilder, providers::get_text_edit_range_in_string,
+};
+
+/// Menu options of the task running `option.get("<??>")`
+pub fn add_completion(
+    builder: &mut CompletionBuilder,
+    string_token: LuaStringToken,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let text_edit_range = get_text_edit_range_in_string(builder, string_token);
+    let semantic_model = &builder.semantic_model;
+    let task = find_running_task(
+        semantic_model.get_db(),
+        semantic_model.get_file_id(),
+        call_expr.syntax(),
+    )?;
+    if task.properties.menu_options.is_empty() {
+        return None;
+    }
+
+    let mut completion_items = Vec::new();
+    for option in &task.properties.menu_options {
+        let text_edit = text_edit_range.map(|text_edit_range| {
+            CompletionTextEdit::Edit(TextEdit {
+                range: text_edit_range,
+                new_text: option.name.value.clone(),
+            })
+        });
+        completion_items.push(CompletionItem {
+            label: option.name.value.clone(),
+            kind: Some(lsp_types::CompletionItemKind::PROPERTY),
+            label_details: Some(CompletionItemLabelDetails {
+                detail: Some(format!(" {}", option.get_usage())),
+                description: option.description.clone(),
+            }),
+            text_edit,
+            ..Default::default()
+        });
+    }
+
+    for completion_item in completion_items {
+        builder.add_completion_item(completion_item)?;
+    }
+
+    Some(())
+}
@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use emmylua_parser::LuaStringToken;
+use lsp_types::{CompletionItem, CompletionItemLabelDetails, CompletionTextEdit, TextEdit};
+
+use crate::handlers::completion::{
+    completion_builder::CompletionBuilder, providers::get_text_edit_range_in_string,
+};
+
+pub fn add_completion(builder: &mut CompletionBuilder, string_token: LuaStringToken) -> Option<()> {
+    let text_edit_range = get_text_edit_range_in_string(builder, string_token);
+
+    // built-in and user toolchains, with the description of any declaration of the name
+    let mut toolchains: BTreeMap<String, Option<String>> = BTreeMap::new();
+    for (_, target) in builder
+        .semantic_model
+        .get_db()
+        .get_xmake_index()
+        .get_all_targets()
+    {
+        if !target.kind.is_toolchain() {
+            continue;
+        }
+        let description = toolchains.entry(target.name.clone()).or_default();
+        if let Some(value) = &target.properties.description {
+            description.get_or_insert_with(|| value.value.clone());
+        }
+    }
+
+    for (name, description) in toolchains {
+        let text_edit = text_edit_range.map(|text_edit_range| {
+            CompletionTextEdit::Edit(TextEdit {
+                range: text_edit_range,
+                new_text: name.clone(),
+            })
+        });
+        let completion_item = CompletionItem {
+            label: name,
+            kind: Some(lsp_types::CompletionItemKind::CONSTANT),
+            label_details: description.map(|description| CompletionItemLabelDetails {
+                detail: None,
+                description: Some(description),
+            }),
+            text_edit,
+            ..Default::default()
+        };
+        builder.add_completion_item(completion_item)?;
+    }
+
+    Some(())
+}
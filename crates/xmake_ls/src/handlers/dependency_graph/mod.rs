@@ -0,0 +1,59 @@
+use lsp_types::{Uri, request::Request};
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use xmake_code_analysis::{XmakeAnalysis, XmakeDependencyGraph};
+
+use crate::context::ServerContextSnapshot;
+
+/// `xmake/dependencyGraph`, the target → target/package/rule graph of a project
+#[derive(Debug)]
+pub enum XmakeDependencyGraphRequest {}
+
+impl Request for XmakeDependencyGraphRequest {
+    type Params = XmakeDependencyGraphParams;
+    type Result = Option<XmakeDependencyGraphResult>;
+    const METHOD: &'static str = "xmake/dependencyGraph";
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeDependencyGraphParams {
+    /// Any file of the project, the files it includes and is included by are used.
+    /// Every workspace file when omitted.
+    pub uri: Option<Uri>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeDependencyGraphResult {
+    pub graph: XmakeDependencyGraph,
+    /// The same graph in Graphviz DOT
+    pub dot: String,
+}
+
+pub async fn on_dependency_graph_handler(
+    context: ServerContextSnapshot,
+    params: XmakeDependencyGraphParams,
+    _: CancellationToken,
+) -> Option<XmakeDependencyGraphResult> {
+    let analysis = context.analysis().read().await;
+    build_dependency_graph(&analysis, params.uri.as_ref())
+}
+
+pub fn build_dependency_graph(
+    analysis: &XmakeAnalysis,
+    uri: Option<&Uri>,
+) -> Option<XmakeDependencyGraphResult> {
+    let db = analysis.compilation.get_db();
+    let graph = match uri {
+        Some(uri) => {
+            let file_id = analysis.get_file_id(uri)?;
+            let file_ids = db.get_xmake_index().get_project_files(file_id);
+            XmakeDependencyGraph::build(db, Some(&file_ids))
+        }
+        None => XmakeDependencyGraph::build(db, None),
+    };
+    let dot = graph.to_dot();
+
+    Some(XmakeDependencyGraphResult { graph, dot })
+}
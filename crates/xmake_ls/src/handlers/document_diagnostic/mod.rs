@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+
+use lsp_types::{
+    ClientCapabilities, Diagnostic, DiagnosticOptions, DiagnosticServerCapabilities,
+    DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
+    FullDocumentDiagnosticReport, RelatedFullDocumentDiagnosticReport,
+    RelatedUnchangedDocumentDiagnosticReport, ServerCapabilities,
+    UnchangedDocumentDiagnosticReport, Uri, WorkspaceDiagnosticParams, WorkspaceDiagnosticReport,
+    WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport,
+    WorkspaceFullDocumentDiagnosticReport, WorkspaceUnchangedDocumentDiagnosticReport,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::context::{DiagnosticMode, ServerContextSnapshot};
+
+use super::RegisterCapabilities;
+
+pub async fn on_document_diagnostic_handler(
+    context: ServerContextSnapshot,
+    params: DocumentDiagnosticParams,
+    cancel_token: CancellationToken,
+) -> Option<DocumentDiagnosticReportResult> {
+    let file_id = context
+        .analysis()
+        .read()
+        .await
+        .get_file_id(&params.text_document.uri);
+    let (result_id, diagnostics) = match file_id {
+        Some(file_id) => context
+            .file_diagnostic()
+            .pull_file_diagnostics(file_id, cancel_token)
+            .await
+            .unwrap_or_default(),
+        None => Default::default(),
+    };
+
+    let report = if params.previous_result_id.as_ref() == Some(&result_id) {
+        DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+            related_documents: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    } else {
+        DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: full_report(result_id, diagnostics),
+        })
+    };
+
+    Some(DocumentDiagnosticReportResult::Report(report))
+}
+
+/// Answers when some file has new diagnostics, otherwise waits for the next change so the
+/// client doesn't poll in a loop
+pub async fn on_workspace_diagnostic_handler(
+    context: ServerContextSnapshot,
+    params: WorkspaceDiagnosticParams,
+    cancel_token: CancellationToken,
+) -> Option<WorkspaceDiagnosticReportResult> {
+    let mut previous_result_ids = params
+        .previous_result_ids
+        .into_iter()
+        .map(|previous| (previous.uri.as_str().to_string(), previous.value))
+        .collect::<HashMap<_, _>>();
+    let file_diagnostic = context.file_diagnostic();
+    let mut changes = file_diagnostic.subscribe_changes();
+
+    loop {
+        changes.mark_unchanged();
+        let files = {
+            let analysis = context.analysis().read().await;
+            analysis
+                .compilation
+                .get_db()
+                .get_module_index()
+                .get_main_workspace_file_ids()
+                .into_iter()
+                .filter_map(|file_id| Some((file_id, analysis.get_uri(file_id)?)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut items = Vec::new();
+        let mut has_changes = false;
+        let mut uris = HashSet::new();
+        for (file_id, uri) in files {
+            let Some((result_id, diagnostics)) = file_diagnostic
+                .pull_file_diagnostics(file_id, cancel_token.clone())
+                .await
+            else {
+                continue;
+            };
+            uris.insert(uri.as_str().to_string());
+            if previous_result_ids.get(uri.as_str()) == Some(&result_id) {
+                items.push(WorkspaceDocumentDiagnosticReport::Unchanged(
+                    WorkspaceUnchangedDocumentDiagnosticReport {
+                        uri,
+                        version: None,
+                        unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                            result_id,
+                        },
+                    },
+                ));
+            } else {
+                has_changes = true;
+                items.push(full_workspace_report(uri, result_id, diagnostics));
+            }
+        }
+
+        for uri in file_diagnostic.take_removed_uris().await {
+            if !uris.contains(uri.as_str()) {
+                has_changes = true;
+                items.push(full_workspace_report(uri, String::new(), Vec::new()));
+            }
+        }
+
+        if has_changes {
+            return Some(WorkspaceDiagnosticReportResult::Report(
+                WorkspaceDiagnosticReport { items },
+            ));
+        }
+
+        // everything is unchanged, answering now would make the client ask again right away
+        previous_result_ids = items
+            .into_iter()
+            .filter_map(|item| match item {
+                WorkspaceDocumentDiagnosticReport::Unchanged(report) => Some((
+                    report.uri.as_str().to_string(),
+                    report.unchanged_document_diagnostic_report.result_id,
+                )),
+                _ => None,
+            })
+            .collect();
+        tokio::select! {
+            changed = changes.changed() => changed.ok()?,
+            _ = cancel_token.cancelled() => return None,
+        }
+    }
+}
+
+fn full_report(result_id: String, diagnostics: Vec<Diagnostic>) -> FullDocumentDiagnosticReport {
+    FullDocumentDiagnosticReport {
+        result_id: (!result_id.is_empty()).then_some(result_id),
+        items: diagnostics,
+    }
+}
+
+fn full_workspace_report(
+    uri: Uri,
+    result_id: String,
+    diagnostics: Vec<Diagnostic>,
+) -> WorkspaceDocumentDiagnosticReport {
+    WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+        uri,
+        version: None,
+        full_document_diagnostic_report: full_report(result_id, diagnostics),
+    })
+}
+
+pub struct DocumentDiagnosticCapabilities;
+
+impl RegisterCapabilities for DocumentDiagnosticCapabilities {
+    fn register_capabilities(
+        server_capabilities: &mut ServerCapabilities,
+        client_capabilities: &ClientCapabilities,
+    ) {
+        // clients without pull support keep getting published diagnostics
+        if DiagnosticMode::from_client_capabilities(client_capabilities) == DiagnosticMode::Push {
+            return;
+        }
+
+        server_capabilities.diagnostic_provider =
+            Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                identifier: Some("xmake".to_string()),
+                inter_file_dependencies: true,
+                workspace_diagnostics: true,
+                ..Default::default()
+            }));
+    }
+}
@@ -28,6 +28,10 @@ impl<'a> DocumentSymbolBuilder<'a> {
         }
     }
 
+    pub fn get_db(&self) -> &'a DbIndex {
+        self.db
+    }
+
     pub fn get_file_id(&self) -> FileId {
         self.document.get_file_id()
     }
@@ -12,10 +12,10 @@ use lsp_types::{
 use stats::{
     build_assign_stat_symbol, build_for_range_stat_symbol, build_for_stat_symbol,
     build_func_stat_symbol, build_if_stat_symbol, build_local_func_stat_symbol,
-    build_local_stat_symbol,
+    build_local_stat_symbol, build_xmake_scope_symbol,
 };
 use tokio_util::sync::CancellationToken;
-use xmake_code_analysis::SemanticModel;
+use xmake_code_analysis::{FileId, SemanticModel, XmakeAnalysis};
 
 use crate::context::ServerContextSnapshot;
 
@@ -29,14 +29,18 @@ pub async fn on_document_symbol(
     let uri = params.text_document.uri;
     let analysis = context.analysis().read().await;
     let file_id = analysis.get_file_id(&uri)?;
-    let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
-    let document_symbol_root = build_document_symbol(&mut semantic_model)?;
-    // remove root file symbol
-    let children = document_symbol_root.children?;
+    let children = document_symbol(&analysis, file_id)?;
     let response = DocumentSymbolResponse::Nested(children);
     Some(response)
 }
 
+pub fn document_symbol(analysis: &XmakeAnalysis, file_id: FileId) -> Option<Vec<DocumentSymbol>> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let document_symbol_root = build_document_symbol(&semantic_model)?;
+    // remove root file symbol
+    document_symbol_root.children
+}
+
 fn build_document_symbol(semantic_model: &SemanticModel) -> Option<DocumentSymbol> {
     let document = semantic_model.get_document();
     let root = semantic_model.get_root();
@@ -88,6 +92,9 @@ fn build_child_document_symbols(
             LuaAst::LuaIfStat(if_stat) => {
                 build_if_stat_symbol(builder, if_stat);
             }
+            LuaAst::LuaCallExprStat(call_expr_stat) => {
+                build_xmake_scope_symbol(builder, call_expr_stat);
+            }
             _ => {}
         }
     }
@@ -1,9 +1,9 @@
 use emmylua_parser::{
-    LuaAssignStat, LuaAstNode, LuaAstToken, LuaForRangeStat, LuaForStat, LuaFuncStat,
-    LuaIfClauseStat, LuaIfStat, LuaLocalFuncStat, LuaLocalStat,
+    LuaAssignStat, LuaAstNode, LuaAstToken, LuaCallExprStat, LuaForRangeStat, LuaForStat,
+    LuaFuncStat, LuaIfClauseStat, LuaIfStat, LuaLocalFuncStat, LuaLocalStat,
 };
 use lsp_types::SymbolKind;
-use xmake_code_analysis::{LuaDeclId, LuaSignatureId, LuaType};
+use xmake_code_analysis::{LuaDeclId, LuaSignatureId, LuaType, XmakeTargetKind};
 
 use super::builder::{DocumentSymbolBuilder, LuaSymbol};
 
@@ -199,3 +199,42 @@ pub fn build_if_stat_symbol(builder: &mut DocumentSymbolBuilder, if_stat: LuaIfS
 
     Some(())
 }
+
+/// `target("app")` and the other scopes, the attributes of `target("app", {...})` are nested in it
+pub fn build_xmake_scope_symbol(
+    builder: &mut DocumentSymbolBuilder,
+    call_expr_stat: LuaCallExprStat,
+) -> Option<()> {
+    let name_arg = call_expr_stat
+        .get_call_expr()?
+        .get_args_list()?
+        .get_args()
+        .next()?;
+    let file_id = builder.get_file_id();
+    let target = builder
+        .get_db()
+        .get_xmake_index()
+        .get_targets(file_id)?
+        .iter()
+        .find(|target| name_arg.get_range().contains_range(target.name_range))?;
+
+    let kind = match target.kind {
+        XmakeTargetKind::Target => SymbolKind::MODULE,
+        XmakeTargetKind::Package => SymbolKind::PACKAGE,
+        _ => SymbolKind::NAMESPACE,
+    };
+    let detail = match &target.properties.kind {
+        Some(kind) => format!("{} {}", target.kind.get_name(), kind.value),
+        None => target.kind.get_name().to_string(),
+    };
+    let symbol = LuaSymbol::with_selection_range(
+        target.name.clone(),
+        Some(detail),
+        kind,
+        call_expr_stat.get_range().cover(target.range),
+        target.name_range,
+    );
+
+    builder.add_node_symbol(call_expr_stat.syntax().clone(), symbol);
+    Some(())
+}
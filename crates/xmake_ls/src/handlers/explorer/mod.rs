@@ -0,0 +1,265 @@
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    time::Duration,
+};
+
+use lsp_types::{Range, Uri, notification::Notification, request::Request};
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use xmake_code_analysis::{XmakeAnalysis, XmakeTarget, XmakeTargetKind, XmakeTargetValue};
+
+use crate::context::{ClientProxy, ServerContextSnapshot};
+
+/// `xmake/targets`, the targets of the workspace
+#[derive(Debug)]
+pub enum XmakeTargetsRequest {}
+
+impl Request for XmakeTargetsRequest {
+    type Params = XmakeExplorerParams;
+    type Result = Vec<XmakeExplorerItem>;
+    const METHOD: &'static str = "xmake/targets";
+}
+
+/// `xmake/packages`, the packages declared with `package()`, not the `add_requires` of a project
+#[derive(Debug)]
+pub enum XmakePackagesRequest {}
+
+impl Request for XmakePackagesRequest {
+    type Params = XmakeExplorerParams;
+    type Result = Vec<XmakeExplorerItem>;
+    const METHOD: &'static str = "xmake/packages";
+}
+
+/// `xmake/options`, the options of the workspace
+#[derive(Debug)]
+pub enum XmakeOptionsRequest {}
+
+impl Request for XmakeOptionsRequest {
+    type Params = XmakeExplorerParams;
+    type Result = Vec<XmakeExplorerItem>;
+    const METHOD: &'static str = "xmake/options";
+}
+
+/// `xmake/rules`, the rules of the workspace
+#[derive(Debug)]
+pub enum XmakeRulesRequest {}
+
+impl Request for XmakeRulesRequest {
+    type Params = XmakeExplorerParams;
+    type Result = Vec<XmakeExplorerItem>;
+    const METHOD: &'static str = "xmake/rules";
+}
+
+/// `xmake/explorerChanged`, sent when a declaration of the workspace is added, removed or
+/// edited, clients showing the explorer request the items again
+#[derive(Debug)]
+pub enum XmakeExplorerChangedNotification {}
+
+impl Notification for XmakeExplorerChangedNotification {
+    type Params = XmakeExplorerChangedParams;
+    const METHOD: &'static str = "xmake/explorerChanged";
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeExplorerChangedParams {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeExplorerParams {
+    /// Any file of the project, the files it includes and is included by are used.
+    /// Every workspace file when omitted.
+    pub uri: Option<Uri>,
+}
+
+/// A declaration of the workspace, sorted by uri and position. Lists are empty and optional
+/// fields are `null` when the declaration does not set them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XmakeExplorerItem {
+    pub name: String,
+    /// `target`, `package`, `option` or `rule`
+    pub kind: String,
+    pub uri: Uri,
+    /// From the declaration to the end of the scope
+    pub range: Range,
+    /// The name string of the declaration
+    pub selection_range: Range,
+    /// `set_kind`, e.g. `binary`
+    pub target_kind: Option<String>,
+    /// `set_description`
+    pub description: Option<String>,
+    /// `add_deps`
+    pub deps: Vec<String>,
+    /// `add_packages`
+    pub packages: Vec<String>,
+    /// `add_rules`
+    pub rules: Vec<String>,
+    /// `add_options`
+    pub options: Vec<String>,
+    /// `add_files`
+    pub files: Vec<String>,
+    /// `add_defines`
+    pub defines: Vec<String>,
+}
+
+pub async fn on_targets_handler(
+    context: ServerContextSnapshot,
+    params: XmakeExplorerParams,
+    _: CancellationToken,
+) -> Vec<XmakeExplorerItem> {
+    let analysis = context.analysis().read().await;
+    build_explorer_items(&analysis, params.uri.as_ref(), XmakeTargetKind::Target)
+}
+
+pub async fn on_packages_handler(
+    context: ServerContextSnapshot,
+    params: XmakeExplorerParams,
+    _: CancellationToken,
+) -> Vec<XmakeExplorerItem> {
+    let analysis = context.analysis().read().await;
+    build_explorer_items(&analysis, params.uri.as_ref(), XmakeTargetKind::Package)
+}
+
+pub async fn on_options_handler(
+    context: ServerContextSnapshot,
+    params: XmakeExplorerParams,
+    _: CancellationToken,
+) -> Vec<XmakeExplorerItem> {
+    let analysis = context.analysis().read().await;
+    build_explorer_items(&analysis, params.uri.as_ref(), XmakeTargetKind::Option)
+}
+
+pub async fn on_rules_handler(
+    context: ServerContextSnapshot,
+    params: XmakeExplorerParams,
+    _: CancellationToken,
+) -> Vec<XmakeExplorerItem> {
+    let analysis = context.analysis().read().await;
+    build_explorer_items(&analysis, params.uri.as_ref(), XmakeTargetKind::Rule)
+}
+
+pub fn build_explorer_items(
+    analysis: &XmakeAnalysis,
+    uri: Option<&Uri>,
+    kind: XmakeTargetKind,
+) -> Vec<XmakeExplorerItem> {
+    let db = analysis.compilation.get_db();
+    let project_files = match uri.and_then(|uri| analysis.get_file_id(uri)) {
+        Some(file_id) => Some(db.get_xmake_index().get_project_files(file_id)),
+        None if uri.is_some() => return Vec::new(),
+        None => None,
+    };
+    let module_index = db.get_module_index();
+    let mut declarations = db
+        .get_xmake_index()
+        .get_all_targets()
+        .filter(|(_, target)| target.kind == kind)
+        .filter(|(file_id, _)| match &project_files {
+            Some(project_files) => project_files.contains(file_id),
+            // only the workspace files, not the std, libraries or builtin targets and toolchains
+            None => module_index.is_main(file_id),
+        })
+        .collect::<Vec<_>>();
+    declarations.sort_by_key(|(file_id, target)| (file_id.id, target.name_range.start()));
+
+    declarations
+        .into_iter()
+        .filter_map(|(file_id, target)| {
+            let document = db.get_vfs().get_document(&file_id)?;
+            Some(to_explorer_item(
+                target,
+                document.get_uri(),
+                document.to_lsp_range(target.range)?,
+                document.to_lsp_range(target.name_range)?,
+            ))
+        })
+        .collect()
+}
+
+fn to_explorer_item(
+    target: &XmakeTarget,
+    uri: Uri,
+    range: Range,
+    selection_range: Range,
+) -> XmakeExplorerItem {
+    let properties = &target.properties;
+    let to_values = |values: &Vec<XmakeTargetValue>| {
+        values
+            .iter()
+            .map(|value| value.value.clone())
+            .collect::<Vec<_>>()
+    };
+    XmakeExplorerItem {
+        name: target.name.clone(),
+        kind: target.kind.get_name().to_string(),
+        uri,
+        range,
+        selection_range,
+        target_kind: properties.kind.as_ref().map(|kind| kind.value.clone()),
+        description: properties
+            .description
+            .as_ref()
+            .map(|description| description.value.clone()),
+        deps: to_values(&properties.deps),
+        packages: to_values(&properties.packages),
+        rules: to_values(&properties.rules),
+        options: to_values(&properties.options),
+        files: to_values(&properties.files),
+        defines: to_values(&properties.defines),
+    }
+}
+
+/// Edits notify the explorer once typing pauses for this long
+pub const EXPLORER_CHANGED_DELAY: Duration = Duration::from_millis(500);
+
+/// Send `xmake/explorerChanged`, clients request the items again
+pub fn send_explorer_changed(client: &ClientProxy) {
+    client.send_notification(
+        XmakeExplorerChangedNotification::METHOD,
+        XmakeExplorerChangedParams::default(),
+    );
+}
+
+/// Hash of what the explorer shows of the declarations in `uris`, compared before and after
+/// updating them. Positions are left out, typing above a target does not change it, and only
+/// workspace files count.
+pub fn get_explorer_hash(analysis: &XmakeAnalysis, uris: &[Uri]) -> u64 {
+    let db = analysis.compilation.get_db();
+    let module_index = db.get_module_index();
+    let mut hasher = DefaultHasher::new();
+    for uri in uris {
+        let Some(file_id) = analysis.get_file_id(uri) else {
+            continue;
+        };
+        if !module_index.is_main(&file_id) {
+            continue;
+        }
+        let Some(targets) = db.get_xmake_index().get_targets(file_id) else {
+            continue;
+        };
+        uri.as_str().hash(&mut hasher);
+        for target in targets {
+            let properties = &target.properties;
+            target.name.hash(&mut hasher);
+            target.kind.hash(&mut hasher);
+            for value in [&properties.kind, &properties.description] {
+                value.as_ref().map(|value| &value.value).hash(&mut hasher);
+            }
+            for values in [
+                &properties.deps,
+                &properties.packages,
+                &properties.rules,
+                &properties.options,
+                &properties.files,
+                &properties.defines,
+            ] {
+                values.len().hash(&mut hasher);
+                for value in values {
+                    value.value.hash(&mut hasher);
+                }
+            }
+        }
+    }
+    hasher.finish()
+}
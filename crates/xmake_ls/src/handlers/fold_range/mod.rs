@@ -3,6 +3,7 @@ mod comment;
 mod expr;
 mod imports;
 mod stats;
+mod xmake;
 
 use builder::FoldingRangeBuilder;
 use comment::build_comment_fold_range;
@@ -18,9 +19,10 @@ use stats::{
     build_if_stat_fold_range, build_repeat_stat_fold_range, build_while_stat_fold_range,
 };
 use tokio_util::sync::CancellationToken;
-use xmake_code_analysis::Emmyrc;
+use xmake::build_xmake_target_fold_ranges;
+use xmake_code_analysis::{Emmyrc, FileId, XmakeAnalysis};
 
-use crate::context::ServerContextSnapshot;
+use crate::context::{ClientId, ServerContextSnapshot};
 
 use super::RegisterCapabilities;
 
@@ -38,6 +40,14 @@ pub async fn on_folding_range_handler(
         .client_config
         .client_id;
     let file_id = analysis.get_file_id(&uri)?;
+    folding_range(&analysis, file_id, client_id)
+}
+
+pub fn folding_range(
+    analysis: &XmakeAnalysis,
+    file_id: FileId,
+    client_id: ClientId,
+) -> Option<Vec<FoldingRange>> {
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     let document = semantic_model.get_document();
     let root = semantic_model.get_root();
@@ -45,6 +55,9 @@ pub async fn on_folding_range_handler(
 
     let mut builder = FoldingRangeBuilder::new(&document, root.clone(), client_id);
     build_folding_ranges(&mut builder, emmyrc);
+    if emmyrc.folding.xmake_blocks {
+        build_xmake_target_fold_ranges(&mut builder, semantic_model.get_db(), file_id);
+    }
     Some(builder.build())
 }
 
@@ -80,7 +93,7 @@ fn build_folding_ranges(builder: &mut FoldingRangeBuilder, emmyrc: &Emmyrc) {
                 build_closure_expr_fold_range(builder, closure);
             }
             LuaAst::LuaIfStat(if_stat) => {
-                build_if_stat_fold_range(builder, if_stat);
+                build_if_stat_fold_range(builder, if_stat, emmyrc.folding.platform_regions);
             }
             _ => {}
         }
@@ -1,9 +1,9 @@
 use emmylua_parser::{
-    LuaDoStat, LuaForRangeStat, LuaForStat, LuaIfStat, LuaRepeatStat, LuaWhileStat,
+    LuaAstNode, LuaDoStat, LuaForRangeStat, LuaForStat, LuaIfStat, LuaRepeatStat, LuaWhileStat,
 };
 use lsp_types::{FoldingRange, FoldingRangeKind};
 
-use super::builder::FoldingRangeBuilder;
+use super::{builder::FoldingRangeBuilder, xmake::build_platform_region_fold_range};
 
 pub fn build_for_stat_fold_range(
     builder: &mut FoldingRangeBuilder,
@@ -103,41 +103,51 @@ pub fn build_do_stat_fold_range(
 pub fn build_if_stat_fold_range(
     builder: &mut FoldingRangeBuilder,
     if_stat: LuaIfStat,
+    platform_regions: bool,
 ) -> Option<()> {
-    let mut collapsed_range_text = Vec::new();
-    if let Some(block) = if_stat.get_block() {
-        if let Some(range) = builder.get_block_collapsed_range(block) {
-            collapsed_range_text.push((range, " .. ".to_string()));
-        }
-    }
-
+    let mut clauses = vec![(
+        if_stat.get_range().start(),
+        if_stat.get_condition_expr(),
+        if_stat.get_block(),
+    )];
     for else_if in if_stat.get_else_if_clause_list() {
-        if let Some(block) = else_if.get_block() {
-            if let Some(range) = builder.get_block_collapsed_range(block) {
-                collapsed_range_text.push((range, " .. ".to_string()));
-            }
-        }
+        clauses.push((
+            else_if.get_range().start(),
+            else_if.get_condition_expr(),
+            else_if.get_block(),
+        ));
     }
-
     if let Some(else_clause) = if_stat.get_else_clause() {
-        if let Some(block) = else_clause.get_block() {
-            if let Some(range) = builder.get_block_collapsed_range(block) {
-                collapsed_range_text.push((range, " .. ".to_string()));
-            }
-        }
+        clauses.push((
+            else_clause.get_range().start(),
+            None,
+            else_clause.get_block(),
+        ));
     }
 
-    for (range, collapsed_text) in collapsed_range_text {
-        let folding_range = FoldingRange {
-            start_line: range.start.line,
-            start_character: Some(range.start.character),
-            end_line: range.end.line,
-            end_character: Some(range.end.character),
-            kind: Some(FoldingRangeKind::Region),
-            collapsed_text: Some(collapsed_text),
+    for (clause_start, condition, block) in clauses {
+        let Some(block) = block else {
+            continue;
         };
+        if platform_regions
+            && let Some(condition) = condition
+            && build_platform_region_fold_range(builder, clause_start, &condition, &block).is_some()
+        {
+            continue;
+        }
 
-        builder.push(folding_range);
+        if let Some(range) = builder.get_block_collapsed_range(block) {
+            let folding_range = FoldingRange {
+                start_line: range.start.line,
+                start_character: Some(range.start.character),
+                end_line: range.end.line,
+                end_character: Some(range.end.character),
+                kind: Some(FoldingRangeKind::Region),
+                collapsed_text: Some(" .. ".to_string()),
+            };
+
+            builder.push(folding_range);
+        }
     }
 
     Some(())
@@ -0,0 +1,132 @@
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaCallExpr, LuaExpr};
+use lsp_types::{FoldingRange, FoldingRangeKind};
+use rowan::TextSize;
+use xmake_code_analysis::{DbIndex, FileId, XmakeTarget};
+
+use super::builder::FoldingRangeBuilder;
+
+/// Condition functions whose `if` sections are folded as named regions
+const PLATFORM_CHECK_FUNCTIONS: &[&str] = &[
+    "is_os",
+    "is_arch",
+    "is_plat",
+    "is_host",
+    "is_subhost",
+    "is_subarch",
+    "is_mode",
+];
+
+pub fn build_xmake_target_fold_ranges(
+    builder: &mut FoldingRangeBuilder,
+    db: &DbIndex,
+    file_id: FileId,
+) -> Option<()> {
+    let targets = db.get_xmake_index().get_targets(file_id)?;
+    for target in targets {
+        build_xmake_target_fold_range(builder, target);
+    }
+
+    Some(())
+}
+
+fn build_xmake_target_fold_range(
+    builder: &mut FoldingRangeBuilder,
+    target: &XmakeTarget,
+) -> Option<()> {
+    let document = builder.get_document();
+    let text = document.get_text_slice(target.range);
+    // `target("name", {...})` is already folded as a table
+    if text.starts_with('{') {
+        return None;
+    }
+
+    let (start_line, start_col) = document.get_line_col(target.range.start())?;
+    let folding_range = match target.end_call_range {
+        // keep `target_end()` visible, same as the `end` of a block
+        Some(end_call_range) => {
+            let (end_line, end_col) = document.get_line_col(end_call_range.start())?;
+            let lsp_range =
+                builder.get_folding_lsp_range(start_line, end_line, start_col, end_col)?;
+            FoldingRange {
+                start_line: lsp_range.start.line,
+                start_character: Some(lsp_range.start.character),
+                end_line: lsp_range.end.line,
+                end_character: Some(lsp_range.end.character),
+                kind: Some(FoldingRangeKind::Region),
+                collapsed_text: Some(" .. ".to_string()),
+            }
+        }
+        // the block ends where the next one starts, don't fold the blank lines between them
+        None => {
+            let content_end = target.range.start() + TextSize::from(text.trim_end().len() as u32);
+            let (end_line, end_col) = document.get_line_col(content_end)?;
+            if end_line == start_line {
+                return None;
+            }
+
+            FoldingRange {
+                start_line: start_line as u32,
+                start_character: Some(start_col as u32),
+                end_line: end_line as u32,
+                end_character: Some(end_col as u32),
+                kind: Some(FoldingRangeKind::Region),
+                collapsed_text: Some(" .. ".to_string()),
+            }
+        }
+    };
+
+    builder.push(folding_range);
+    Some(())
+}
+
+/// Fold an `if`/`elseif` clause that checks the platform, e.g. `is_plat("windows")`, as a region
+/// from its keyword to the end of its body, named after the condition like a `-- region` comment
+pub fn build_platform_region_fold_range(
+    builder: &mut FoldingRangeBuilder,
+    clause_start: TextSize,
+    condition: &LuaExpr,
+    block: &LuaBlock,
+) -> Option<()> {
+    let is_platform_check = condition.descendants::<LuaCallExpr>().any(|call_expr| {
+        let Some(LuaExpr::NameExpr(name_expr)) = call_expr.get_prefix_expr() else {
+            return false;
+        };
+        name_expr
+            .get_name_text()
+            .is_some_and(|name| PLATFORM_CHECK_FUNCTIONS.contains(&name.as_str()))
+    });
+    if !is_platform_check {
+        return None;
+    }
+
+    let document = builder.get_document();
+    let block_range = block.get_range();
+    let block_text = document.get_text_slice(block_range).trim_end();
+    if block_text.trim_start().is_empty() {
+        return None;
+    }
+    let content_end = block_range.start() + TextSize::from(block_text.len() as u32);
+    let (start_line, start_col) = document.get_line_col(clause_start)?;
+    let (end_line, end_col) = document.get_line_col(content_end)?;
+    if end_line == start_line {
+        return None;
+    }
+
+    let condition_text = condition.syntax().text().to_string();
+    let folding_range = FoldingRange {
+        start_line: start_line as u32,
+        start_character: Some(start_col as u32),
+        end_line: end_line as u32,
+        end_character: Some(end_col as u32),
+        kind: Some(FoldingRangeKind::Region),
+        collapsed_text: Some(
+            condition_text
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+    };
+
+    builder.push(folding_range);
+    Some(())
+}
@@ -5,6 +5,7 @@ mod hover_builder;
 mod hover_humanize;
 mod keyword_hover;
 mod std_hover;
+mod target_hover_builder;
 
 use super::RegisterCapabilities;
 use crate::context::ServerContextSnapshot;
@@ -21,6 +22,7 @@ use lsp_types::{
 };
 use rowan::TokenAtOffset;
 pub use std_hover::{hover_std_description, is_std};
+use target_hover_builder::hover_xmake_target;
 use tokio_util::sync::CancellationToken;
 use xmake_code_analysis::{FileId, XmakeAnalysis};
 
@@ -81,6 +83,9 @@ pub fn hover(analysis: &XmakeAnalysis, file_id: FileId, position: Position) -> O
             });
         }
         _ => {
+            if let Some(hover) = hover_xmake_target(&semantic_model, token.clone()) {
+                return Some(hover);
+            }
             let semantic_info = semantic_model.get_semantic_info(token.clone().into())?;
             let db = semantic_model.get_db();
             let document = semantic_model.get_document();
@@ -0,0 +1,214 @@
+use emmylua_parser::{LuaAstToken, LuaStringToken, LuaSyntaxToken};
+use lsp_types::{Hover, HoverContents, MarkupContent};
+use rowan::TextRange;
+use xmake_code_analysis::{
+    DbIndex, FileId, SemanticModel, XmakeFunction, XmakeTarget, XmakeTargetKind, XmakeTargetValue,
+    find_toolchains, get_string_api, get_task_usage, get_toolchain_arg, get_xmake_function_by_name,
+};
+
+/// Hover for the name of a target, package, rule, option, task or toolchain, e.g. `target("core")` or
+/// `add_deps("core")`. Everything known about the declarations of that name is merged.
+#[derive(Debug)]
+pub struct TargetHoverBuilder<'a> {
+    db: &'a DbIndex,
+    name: String,
+    kind: XmakeTargetKind,
+    set_kind: Option<String>,
+    description: Option<String>,
+    /// Command line usage and options of a task
+    usage: Option<(String, Vec<String>)>,
+    sections: Vec<(&'static str, Vec<String>)>,
+    locations: Vec<String>,
+}
+
+impl<'a> TargetHoverBuilder<'a> {
+    pub fn new(db: &'a DbIndex, name: String, kind: XmakeTargetKind) -> Self {
+        Self {
+            db,
+            name,
+            kind,
+            set_kind: None,
+            description: None,
+            usage: None,
+            sections: vec![
+                ("deps", Vec::new()),
+                ("packages", Vec::new()),
+                ("rules", Vec::new()),
+                ("options", Vec::new()),
+                ("files", Vec::new()),
+                ("defines", Vec::new()),
+            ],
+            locations: Vec::new(),
+        }
+    }
+
+    pub fn add_target(&mut self, file_id: FileId, target: &XmakeTarget) {
+        let properties = &target.properties;
+        if let Some(kind) = &properties.kind {
+            self.set_kind = Some(kind.value.clone());
+        }
+        if let Some(description) = &properties.description {
+            self.description = Some(description.value.clone());
+        }
+        if !properties.menu_options.is_empty() {
+            let options = properties
+                .menu_options
+                .iter()
+                .map(|option| match &option.description {
+                    Some(description) => format!("`{}`: {}", option.get_usage(), description),
+                    None => format!("`{}`", option.get_usage()),
+                })
+                .collect();
+            self.usage = Some((get_task_usage(target), options));
+        }
+
+        let values = [
+            &properties.deps,
+            &properties.packages,
+            &properties.rules,
+            &properties.options,
+            &properties.files,
+            &properties.defines,
+        ];
+        for ((_, section), values) in self.sections.iter_mut().zip(values) {
+            add_unique_values(section, values);
+        }
+
+        if let Some(location) = self.get_location(file_id, target.name_range) {
+            self.locations.push(location);
+        }
+    }
+
+    fn get_location(&self, file_id: FileId, range: TextRange) -> Option<String> {
+        let vfs = self.db.get_vfs();
+        let document = vfs.get_document(&file_id)?;
+        let line = document.get_line(range.start())? + 1;
+        let uri = vfs.get_uri(&file_id)?;
+        let file_name = document.get_file_name().unwrap_or_else(|| uri.to_string());
+        Some(format!(
+            "[{}:{}]({}#L{})",
+            file_name,
+            line,
+            uri.as_str(),
+            line
+        ))
+    }
+
+    pub fn build_hover_result(&self, range: Option<lsp_types::Range>) -> Option<Hover> {
+        if self.locations.is_empty() {
+            return None;
+        }
+
+        let mut result = format!("```lua\n{}(\"{}\")\n```\n", self.kind.get_name(), self.name);
+        if let Some(description) = &self.description {
+            result.push_str(&format!("\n{}\n", description));
+        }
+
+        let mut properties = String::new();
+        if let Some(kind) = &self.set_kind {
+            properties.push_str(&format!("\n**kind**: `{}`\n", kind));
+        }
+        if let Some((usage, options)) = &self.usage {
+            properties.push_str(&format!("\n**usage**: `{}`\n\n", usage));
+            for option in options {
+                properties.push_str(&format!("- {}\n", option));
+            }
+        }
+        for (title, values) in &self.sections {
+            if values.is_empty() {
+                continue;
+            }
+            let values = values
+                .iter()
+                .map(|value| format!("`{}`", value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            properties.push_str(&format!("\n**{}**: {}\n", title, values));
+        }
+        if !properties.is_empty() {
+            result.push_str("\n---\n");
+            result.push_str(&properties);
+        }
+
+        result.push_str("\n---\n");
+        for location in &self.locations {
+            result.push_str(&format!("\nDeclared in {}\n", location));
+        }
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: lsp_types::MarkupKind::Markdown,
+                value: result.trim().to_string(),
+            }),
+            range,
+        })
+    }
+}
+
+fn add_unique_values(section: &mut Vec<String>, values: &[XmakeTargetValue]) {
+    for value in values {
+        if !section.contains(&value.value) {
+            section.push(value.value.clone());
+        }
+    }
+}
+
+/// The kind of scope a string names, from the call it is passed to
+fn get_named_kind(api: &str) -> Option<XmakeTargetKind> {
+    match get_xmake_function_by_name(api) {
+        Some(XmakeFunction::Target) => return Some(XmakeTargetKind::Target),
+        Some(XmakeFunction::Package) => return Some(XmakeTargetKind::Package),
+        Some(XmakeFunction::Rule) => return Some(XmakeTargetKind::Rule),
+        Some(XmakeFunction::Option) => return Some(XmakeTargetKind::Option),
+        Some(XmakeFunction::Task) => return Some(XmakeTargetKind::Task),
+        Some(XmakeFunction::Toolchain) => return Some(XmakeTargetKind::Toolchain),
+        _ => {}
+    }
+
+    match api {
+        "add_deps" => Some(XmakeTargetKind::Target),
+        "add_packages" => Some(XmakeTargetKind::Package),
+        "add_rules" => Some(XmakeTargetKind::Rule),
+        "add_options" => Some(XmakeTargetKind::Option),
+        _ => None,
+    }
+}
+
+pub fn hover_xmake_target(semantic_model: &SemanticModel, token: LuaSyntaxToken) -> Option<Hover> {
+    let string_token = LuaStringToken::cast(token)?;
+    let (api, _) = get_string_api(&string_token)?;
+    let name = string_token.get_value();
+
+    let db = semantic_model.get_db();
+    let (kind, mut targets) = match get_toolchain_arg(&string_token) {
+        Some(_) => (XmakeTargetKind::Toolchain, find_toolchains(db, &name)?),
+        None => {
+            let kind = get_named_kind(&api)?;
+            let targets = db
+                .get_xmake_index()
+                .find_targets(&name, kind)
+                .collect::<Vec<_>>();
+            (kind, targets)
+        }
+    };
+    targets.sort_by(|(a_file, a), (b_file, b)| {
+        a_file
+            .partial_cmp(b_file)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.name_range.start().cmp(&b.name_range.start()))
+    });
+
+    // `gcc-11` shows the `gcc` toolchain
+    let name = targets
+        .first()
+        .map_or_else(|| name.clone(), |(_, target)| target.name.clone());
+    let mut builder = TargetHoverBuilder::new(db, name, kind);
+    for (file_id, target) in targets {
+        builder.add_target(file_id, target);
+    }
+
+    let range = semantic_model
+        .get_document()
+        .to_lsp_range(string_token.get_range());
+    builder.build_hover_result(range)
+}
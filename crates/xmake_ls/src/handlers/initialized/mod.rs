@@ -3,7 +3,7 @@ mod collect_files;
 mod locale;
 mod xmake_initialize;
 
-use std::{path::PathBuf, str::FromStr, sync::Arc};
+use std::{path::PathBuf, str::FromStr, sync::Arc, time::Duration};
 
 use crate::{
     cmd_args::CmdArgs,
@@ -18,7 +18,7 @@ use crate::{
     logger::init_logger,
 };
 pub use client_config::{ClientConfig, get_client_config};
-use collect_files::collect_files;
+pub(crate) use collect_files::collect_files;
 use lsp_types::InitializeParams;
 use tokio::sync::RwLock;
 use xmake_code_analysis::{
@@ -73,10 +73,7 @@ pub async fn initialized_handler(
     // emmyrc.runtime.require_like_function = vec![];
     emmyrc.runtime.version = EmmyrcLuaVersion::Lua54;
     emmyrc.runtime.require_pattern = vec!["?.xmake.lua".to_string(), "?.lua".to_string()];
-    emmyrc.diagnostics.disable = vec![
-        DiagnosticCode::UnnecessaryIf,
-        DiagnosticCode::UndefinedGlobal,
-    ];
+    emmyrc.diagnostics.disable = vec![DiagnosticCode::UnnecessaryIf];
 
     let arc_emmyrc = Arc::new(emmyrc);
 
@@ -102,6 +99,12 @@ pub async fn initialized_handler(
             WorkspaceFileMatcher::new(include, exclude, exclude_dir);
         workspace_manager.set_workspace_initialized();
         log::info!("workspace manager initialized");
+        workspace_manager
+            .update_compile_commands(Duration::ZERO)
+            .await;
+        workspace_manager
+            .notify_explorer_changed(Duration::ZERO)
+            .await;
     }
     register_files_watch(context.clone(), &params.capabilities).await;
     Some(())
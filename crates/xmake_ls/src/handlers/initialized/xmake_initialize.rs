@@ -1,5 +1,6 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use emmylua_parser::LuaVersionNumber;
 use lsp_types::ShowMessageParams;
 use xmake_code_analysis::WorkspaceId;
 
@@ -43,6 +44,16 @@ pub async fn init_xmake(context: &ServerContextSnapshot) {
 
     // set version to workspace
     {
+        let mut analysis = context.analysis().write().await;
+        analysis
+            .compilation
+            .get_db_mut()
+            .get_xmake_index_mut()
+            .set_xmake_version(LuaVersionNumber::new(
+                xmake_version.major,
+                xmake_version.minor,
+                xmake_version.patch,
+            ));
         let mut workspace = context.workspace_manager().write().await;
         workspace.xmake_version = xmake_version;
     }
@@ -88,13 +99,23 @@ pub async fn init_xmake(context: &ServerContextSnapshot) {
     let xmake_workspace = vec![
         xmake_program_dir.join("core/sandbox/modules/import"),
         xmake_program_dir.join("includes"),
+        xmake_program_dir.join("toolchains"),
         // other xmake lib paths can be added here if needed
     ];
 
     analysis.add_custom_workspace(WorkspaceId::BUILTIN_IMPORT, xmake_workspace[0].clone());
     analysis.add_custom_workspace(WorkspaceId::BUILTIN_INCLUDE, xmake_workspace[1].clone());
+    analysis.add_custom_workspace(WorkspaceId::BUILTIN_TOOLCHAIN, xmake_workspace[2].clone());
 
-    let xmake_lib_files = collect_files(&xmake_workspace, &emmyrc);
+    // only the declarations of toolchains, not their check and load scripts
+    let xmake_lib_files = collect_files(&xmake_workspace, &emmyrc)
+        .into_iter()
+        .filter(|file| {
+            let path = Path::new(&file.path);
+            !path.starts_with(&xmake_workspace[2])
+                || path.file_name().is_some_and(|name| name == "xmake.lua")
+        })
+        .collect::<Vec<_>>();
 
     let files: Vec<(PathBuf, Option<String>)> = xmake_lib_files
         .into_iter()
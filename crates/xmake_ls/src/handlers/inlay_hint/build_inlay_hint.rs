@@ -20,6 +20,7 @@ use rowan::TokenAtOffset;
 use crate::handlers::completion::extract_index_member_alias;
 use crate::handlers::definition::compare_function_types;
 use crate::handlers::inlay_hint::build_function_hint::{build_closure_hint, build_label_parts};
+use crate::handlers::inlay_hint::build_xmake_hint::build_xmake_hints;
 
 pub fn build_inlay_hints(semantic_model: &SemanticModel) -> Option<Vec<InlayHint>> {
     let mut result = Vec::new();
@@ -48,6 +49,8 @@ pub fn build_inlay_hints(semantic_model: &SemanticModel) -> Option<Vec<InlayHint
         }
     }
 
+    build_xmake_hints(semantic_model, &mut result);
+
     Some(result)
 }
 
@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaLiteralToken};
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel};
+use rowan::TextSize;
+use xmake_code_analysis::{
+    DbIndex, FileId, SemanticModel, XmakeFunction, XmakeTarget, get_xmake_function,
+};
+
+pub fn build_xmake_hints(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+) -> Option<()> {
+    let hint_config = &semantic_model.get_emmyrc().hint;
+    if hint_config.includes_hint {
+        for call_expr in semantic_model.get_root().descendants::<LuaCallExpr>() {
+            if get_xmake_function(&call_expr) == Some(XmakeFunction::Includes) {
+                build_includes_hint(semantic_model, result, call_expr);
+            }
+        }
+    }
+
+    let file_id = semantic_model.get_file_id();
+    let targets = semantic_model
+        .get_db()
+        .get_xmake_index()
+        .get_targets(file_id)?;
+    for target in targets {
+        if hint_config.scope_end_hint {
+            build_scope_end_hint(semantic_model, result, target);
+        }
+        if hint_config.target_kind_hint {
+            build_target_kind_hint(semantic_model, result, target);
+        }
+    }
+
+    Some(())
+}
+
+fn build_scope_end_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    target: &XmakeTarget,
+) -> Option<()> {
+    if target.end_call_range.is_some() {
+        return None;
+    }
+
+    let document = semantic_model.get_document();
+    let text = document.get_text_slice(target.range);
+    // `target("name", {...})` ends with its table
+    if text.starts_with('{') {
+        return None;
+    }
+    let content_end = target.range.start() + TextSize::from(text.trim_end().len() as u32);
+    let start_line = document.get_line(target.range.start())?;
+    if document.get_line(content_end)? == start_line {
+        return None;
+    }
+
+    let hint = InlayHint {
+        kind: None,
+        label: InlayHintLabel::String(format!(
+            "-- end {} \"{}\"",
+            target.kind.get_name(),
+            target.name
+        )),
+        position: document.to_lsp_position(content_end)?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+    Some(())
+}
+
+fn build_target_kind_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    target: &XmakeTarget,
+) -> Option<()> {
+    if !target.kind.is_target() {
+        return None;
+    }
+    let kind = target.properties.kind.as_ref()?;
+
+    let document = semantic_model.get_document();
+    // after the closing paren of `target("name")`
+    let call_expr = semantic_model
+        .get_root()
+        .syntax()
+        .token_at_offset(target.name_range.start())
+        .right_biased()?
+        .parent_ancestors()
+        .find_map(LuaCallExpr::cast)?;
+    let hint = InlayHint {
+        kind: Some(InlayHintKind::TYPE),
+        label: InlayHintLabel::String(format!(": {}", kind.value)),
+        position: document.to_lsp_position(call_expr.get_range().end())?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: None,
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+    Some(())
+}
+
+fn build_includes_hint(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<InlayHint>,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let db = semantic_model.get_db();
+    let file_id = semantic_model.get_file_id();
+    let mut visited = HashSet::from([file_id]);
+    let mut found = false;
+    let mut count = 0;
+    for arg in call_expr.get_args_list()?.get_args() {
+        let LuaExpr::LiteralExpr(literal) = arg else {
+            continue;
+        };
+        let Some(LuaLiteralToken::String(string_token)) = literal.get_literal() else {
+            continue;
+        };
+        let Some(module_info) =
+            db.get_module_index()
+                .find_include(db, &string_token.get_value(), file_id)
+        else {
+            continue;
+        };
+        found = true;
+        count += count_targets(db, module_info.file_id, &mut visited);
+    }
+    if !found {
+        return None;
+    }
+
+    let document = semantic_model.get_document();
+    let label = if count == 1 {
+        "1 target".to_string()
+    } else {
+        format!("{} targets", count)
+    };
+    let hint = InlayHint {
+        kind: None,
+        label: InlayHintLabel::String(label),
+        position: document.to_lsp_position(call_expr.get_range().end())?,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    };
+    result.push(hint);
+    Some(())
+}
+
+/// Targets declared in `file_id` and in the files it includes
+fn count_targets(db: &DbIndex, file_id: FileId, visited: &mut HashSet<FileId>) -> usize {
+    if !visited.insert(file_id) {
+        return 0;
+    }
+
+    let xmake_index = db.get_xmake_index();
+    let mut count = xmake_index
+        .get_targets(file_id)
+        .map(|targets| {
+            targets
+                .iter()
+                .filter(|target| target.kind.is_target())
+                .count()
+        })
+        .unwrap_or(0);
+    if let Some(includes) = xmake_index.get_includes(file_id) {
+        for include_file_id in includes {
+            count += count_targets(db, *include_file_id, visited);
+        }
+    }
+
+    count
+}
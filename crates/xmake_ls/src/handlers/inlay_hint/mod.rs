@@ -1,5 +1,6 @@
 mod build_function_hint;
 mod build_inlay_hint;
+mod build_xmake_hint;
 
 use build_inlay_hint::build_inlay_hints;
 use lsp_types::{
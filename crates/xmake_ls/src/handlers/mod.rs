@@ -5,7 +5,9 @@ mod command;
 mod completion;
 mod configuration;
 mod definition;
+mod dependency_graph;
 mod document_color;
+mod document_diagnostic;
 mod document_formatting;
 mod document_highlight;
 mod document_link;
@@ -13,6 +15,7 @@ mod document_range_formatting;
 mod document_selection_range;
 mod document_symbol;
 mod document_type_format;
+mod explorer;
 mod fold_range;
 mod hover;
 mod implementation;
@@ -35,6 +38,7 @@ mod test;
 #[cfg(test)]
 mod test_lib;
 
+pub use explorer::send_explorer_changed;
 pub use initialized::{ClientConfig, init_analysis, initialized_handler};
 use lsp_types::{ClientCapabilities, ServerCapabilities};
 pub use notification_handler::on_notification_handler;
@@ -69,6 +73,7 @@ capabilities!(modules: {
     text_document => TextDocumentCapabilities,
     document_symbol => DocumentSymbolCapabilities,
     document_color => DocumentColorCapabilities,
+    document_diagnostic => DocumentDiagnosticCapabilities,
     document_link => DocumentLinkCapabilities,
     document_selection_range => DocumentSelectionRangeCapabilities,
     document_highlight => DocumentHighlightCapabilities,
@@ -3,15 +3,15 @@ use std::error::Error;
 use log::warn;
 use lsp_server::Notification;
 use lsp_types::{
-    CancelParams, NumberOrString,
+    CancelParams, NumberOrString, WorkDoneProgressCancelParams,
     notification::{
         Cancel, DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles,
         DidCloseTextDocument, DidOpenTextDocument, DidRenameFiles, DidSaveTextDocument,
-        Notification as LspNotification, SetTrace,
+        Notification as LspNotification, SetTrace, WorkDoneProgressCancel,
     },
 };
 
-use crate::context::ServerContext;
+use crate::context::{ServerContext, ServerContextSnapshot};
 
 use super::{
     configuration::on_did_change_configuration,
@@ -65,6 +65,7 @@ pub async fn on_notification_handler(
     dispatch_notification!(notification, server_context, {
         sync: {
             DidChangeTextDocument => on_did_change_text_document,
+            WorkDoneProgressCancel => on_work_done_progress_cancel,
         }
         async: {
             DidOpenTextDocument => on_did_open_text_document,
@@ -88,3 +89,10 @@ async fn handle_cancel(server_context: &mut ServerContext, params: CancelParams)
 
     server_context.cancel(req_id).await;
 }
+
+async fn on_work_done_progress_cancel(
+    context: ServerContextSnapshot,
+    params: WorkDoneProgressCancelParams,
+) {
+    context.status_bar().cancel_progress_task(&params.token);
+}
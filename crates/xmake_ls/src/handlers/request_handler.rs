@@ -5,12 +5,13 @@ use lsp_server::{Request, Response};
 use lsp_types::request::{
     CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
     CodeActionRequest, CodeLensRequest, CodeLensResolve, ColorPresentationRequest, Completion,
-    DocumentColor, DocumentHighlightRequest, DocumentLinkRequest, DocumentLinkResolve,
-    DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest, Formatting, GotoDefinition,
-    GotoImplementation, HoverRequest, InlayHintRequest, InlayHintResolveRequest,
+    DocumentColor, DocumentDiagnosticRequest, DocumentHighlightRequest, DocumentLinkRequest,
+    DocumentLinkResolve, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest, Formatting,
+    GotoDefinition, GotoImplementation, HoverRequest, InlayHintRequest, InlayHintResolveRequest,
     InlineValueRequest, OnTypeFormatting, PrepareRenameRequest, RangeFormatting, References,
     Rename, Request as LspRequest, ResolveCompletionItem, SelectionRangeRequest,
-    SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
+    SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceDiagnosticRequest,
+    WorkspaceSymbolRequest,
 };
 
 use crate::{context::ServerContext, handlers::document_type_format::on_type_formatting_handler};
@@ -24,13 +25,19 @@ use super::{
     command::on_execute_command_handler,
     completion::{on_completion_handler, on_completion_resolve_handler},
     definition::on_goto_definition_handler,
+    dependency_graph::{XmakeDependencyGraphRequest, on_dependency_graph_handler},
     document_color::{on_document_color, on_document_color_presentation},
+    document_diagnostic::{on_document_diagnostic_handler, on_workspace_diagnostic_handler},
     document_formatting::on_formatting_handler,
     document_highlight::on_document_highlight_handler,
     document_link::{on_document_link_handler, on_document_link_resolve_handler},
     document_range_formatting::on_range_formatting_handler,
     document_selection_range::on_document_selection_range_handle,
     document_symbol::on_document_symbol,
+    explorer::{
+        XmakeOptionsRequest, XmakePackagesRequest, XmakeRulesRequest, XmakeTargetsRequest,
+        on_options_handler, on_packages_handler, on_rules_handler, on_targets_handler,
+    },
     fold_range::on_folding_range_handler,
     hover::on_hover,
     implementation::on_implementation_handler,
@@ -110,6 +117,13 @@ pub async fn on_request_handler(
         CallHierarchyPrepare => on_prepare_call_hierarchy_handler,
         CallHierarchyIncomingCalls => on_incoming_calls_handler,
         CallHierarchyOutgoingCalls => on_outgoing_calls_handler,
+        XmakeDependencyGraphRequest => on_dependency_graph_handler,
+        XmakeTargetsRequest => on_targets_handler,
+        XmakePackagesRequest => on_packages_handler,
+        XmakeOptionsRequest => on_options_handler,
+        XmakeRulesRequest => on_rules_handler,
+        DocumentDiagnosticRequest => on_document_diagnostic_handler,
+        WorkspaceDiagnosticRequest => on_workspace_diagnostic_handler,
     });
 
     Ok(())
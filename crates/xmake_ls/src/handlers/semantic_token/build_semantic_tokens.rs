@@ -1,5 +1,7 @@
 use super::{
-    SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES, semantic_token_builder::SemanticBuilder,
+    SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES,
+    semantic_token_builder::SemanticBuilder,
+    xmake_semantic_tokens::{build_xmake_name_semantic_tokens, get_xmake_api_modifier},
 };
 use crate::context::ClientId;
 use emmylua_parser::{
@@ -487,7 +489,18 @@ fn build_node_semantic_token(
 
             match prefix {
                 LuaExpr::NameExpr(name_expr) => {
+                    build_xmake_name_semantic_tokens(builder, &call_expr);
                     let name = name_expr.get_name_token()?;
+                    if let Some(modifier) =
+                        get_xmake_api_modifier(semantic_model, &call_expr, &name_expr)
+                    {
+                        builder.push_with_modifiers(
+                            name.syntax(),
+                            SemanticTokenType::FUNCTION,
+                            &[SemanticTokenModifier::DEFAULT_LIBRARY, modifier],
+                        );
+                        return Some(());
+                    }
                     if let Some(prefix_type) = prefix_type {
                         match prefix_type {
                             LuaType::Signature(signature) => {
@@ -1,5 +1,6 @@
 mod build_semantic_tokens;
 mod semantic_token_builder;
+mod xmake_semantic_tokens;
 
 use crate::context::{ClientId, ServerContextSnapshot};
 use build_semantic_tokens::build_semantic_tokens;
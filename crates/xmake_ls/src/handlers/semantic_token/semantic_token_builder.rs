@@ -31,6 +31,12 @@ pub const SEMANTIC_TOKEN_TYPES: &[SemanticTokenType] = &[
     SemanticTokenType::REGEXP,
     SemanticTokenType::OPERATOR,
     SemanticTokenType::DECORATOR,
+    XMAKE_TARGET_TOKEN_TYPE,
+    XMAKE_PACKAGE_TOKEN_TYPE,
+    XMAKE_RULE_TOKEN_TYPE,
+    XMAKE_OPTION_TOKEN_TYPE,
+    XMAKE_TASK_TOKEN_TYPE,
+    XMAKE_TOOLCHAIN_TOKEN_TYPE,
 ];
 
 pub const SEMANTIC_TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
@@ -44,8 +50,31 @@ pub const SEMANTIC_TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
     SemanticTokenModifier::MODIFICATION,
     SemanticTokenModifier::DOCUMENTATION,
     SemanticTokenModifier::DEFAULT_LIBRARY,
+    XMAKE_SCOPE_MODIFIER,
+    XMAKE_DESCRIPTION_MODIFIER,
+    XMAKE_CONDITION_MODIFIER,
+    XMAKE_SCRIPT_MODIFIER,
 ];
 
+/// String literals naming a target, e.g. `target("app")` or `add_deps("app")`
+pub const XMAKE_TARGET_TOKEN_TYPE: SemanticTokenType = SemanticTokenType::new("xmakeTarget");
+pub const XMAKE_PACKAGE_TOKEN_TYPE: SemanticTokenType = SemanticTokenType::new("xmakePackage");
+pub const XMAKE_RULE_TOKEN_TYPE: SemanticTokenType = SemanticTokenType::new("xmakeRule");
+pub const XMAKE_OPTION_TOKEN_TYPE: SemanticTokenType = SemanticTokenType::new("xmakeOption");
+pub const XMAKE_TASK_TOKEN_TYPE: SemanticTokenType = SemanticTokenType::new("xmakeTask");
+pub const XMAKE_TOOLCHAIN_TOKEN_TYPE: SemanticTokenType = SemanticTokenType::new("xmakeToolchain");
+
+/// Scope openers and closers: `target`, `target_end`, `package`, ...
+pub const XMAKE_SCOPE_MODIFIER: SemanticTokenModifier = SemanticTokenModifier::new("xmakeScope");
+/// Description APIs: `add_*` and `set_*`
+pub const XMAKE_DESCRIPTION_MODIFIER: SemanticTokenModifier =
+    SemanticTokenModifier::new("xmakeDescription");
+/// Condition functions: `is_plat`, `has_config`, ...
+pub const XMAKE_CONDITION_MODIFIER: SemanticTokenModifier =
+    SemanticTokenModifier::new("xmakeCondition");
+/// Script hooks: `on_*`, `before_*` and `after_*`
+pub const XMAKE_SCRIPT_MODIFIER: SemanticTokenModifier = SemanticTokenModifier::new("xmakeScript");
+
 #[derive(Debug)]
 struct BasicSemanticTokenData {
     line: u32,
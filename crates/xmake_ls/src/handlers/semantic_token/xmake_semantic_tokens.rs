@@ -0,0 +1,132 @@
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaLiteralToken, LuaNameExpr};
+use lsp_types::{SemanticTokenModifier, SemanticTokenType};
+use xmake_code_analysis::{
+    LuaSemanticDeclId, SemanticDeclLevel, SemanticModel, XmakeFunction, get_xmake_function,
+};
+
+use super::semantic_token_builder::{
+    SemanticBuilder, XMAKE_CONDITION_MODIFIER, XMAKE_DESCRIPTION_MODIFIER, XMAKE_OPTION_TOKEN_TYPE,
+    XMAKE_PACKAGE_TOKEN_TYPE, XMAKE_RULE_TOKEN_TYPE, XMAKE_SCOPE_MODIFIER, XMAKE_SCRIPT_MODIFIER,
+    XMAKE_TARGET_TOKEN_TYPE, XMAKE_TASK_TOKEN_TYPE, XMAKE_TOOLCHAIN_TOKEN_TYPE,
+};
+
+const CONDITION_FUNCTIONS: &[&str] = &[
+    "is_os",
+    "is_arch",
+    "is_plat",
+    "is_host",
+    "is_subhost",
+    "is_subarch",
+    "is_mode",
+    "is_kind",
+    "is_config",
+    "has_config",
+    "has_package",
+];
+
+/// The modifier of a call to a builtin xmake api, describing its role in the DSL
+pub fn get_xmake_api_modifier(
+    semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
+    name_expr: &LuaNameExpr,
+) -> Option<SemanticTokenModifier> {
+    // a user function named `add_xxx` is not part of the DSL
+    let LuaSemanticDeclId::LuaDecl(decl_id) = semantic_model.find_decl(
+        name_expr.syntax().clone().into(),
+        SemanticDeclLevel::default(),
+    )?
+    else {
+        return None;
+    };
+    if !semantic_model
+        .get_db()
+        .get_module_index()
+        .is_meta_file(&decl_id.file_id)
+    {
+        return None;
+    }
+
+    if let Some(xmake_function) = get_xmake_function(call_expr)
+        && (get_scope_name_token_type(xmake_function).is_some() || is_scope_end(xmake_function))
+    {
+        return Some(XMAKE_SCOPE_MODIFIER);
+    }
+
+    let name = name_expr.get_name_text()?;
+    if CONDITION_FUNCTIONS.contains(&name.as_str()) {
+        Some(XMAKE_CONDITION_MODIFIER)
+    } else if name.starts_with("on_") || name.starts_with("before_") || name.starts_with("after_") {
+        Some(XMAKE_SCRIPT_MODIFIER)
+    } else if name.starts_with("add_") || name.starts_with("set_") {
+        Some(XMAKE_DESCRIPTION_MODIFIER)
+    } else {
+        None
+    }
+}
+
+/// Mark the string arguments that name a target, package, rule, option, task or toolchain
+pub fn build_xmake_name_semantic_tokens(
+    builder: &mut SemanticBuilder,
+    call_expr: &LuaCallExpr,
+) -> Option<()> {
+    let LuaExpr::NameExpr(name_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+
+    let args = call_expr.get_args_list()?.get_args();
+    if let Some(ty) = get_xmake_function(call_expr).and_then(get_scope_name_token_type) {
+        let LuaExpr::LiteralExpr(literal) = args.into_iter().next()? else {
+            return None;
+        };
+        let LuaLiteralToken::String(string_token) = literal.get_literal()? else {
+            return None;
+        };
+        builder.push_with_modifier(
+            string_token.syntax(),
+            ty,
+            SemanticTokenModifier::DECLARATION,
+        );
+        return Some(());
+    }
+
+    let ty = match name_expr.get_name_text()?.as_str() {
+        "add_deps" => XMAKE_TARGET_TOKEN_TYPE,
+        "add_packages" => XMAKE_PACKAGE_TOKEN_TYPE,
+        "add_rules" => XMAKE_RULE_TOKEN_TYPE,
+        "add_options" => XMAKE_OPTION_TOKEN_TYPE,
+        _ => return None,
+    };
+    for arg in args {
+        if let LuaExpr::LiteralExpr(literal) = arg
+            && let Some(LuaLiteralToken::String(string_token)) = literal.get_literal()
+        {
+            builder.push(string_token.syntax(), ty.clone());
+        }
+    }
+
+    Some(())
+}
+
+fn get_scope_name_token_type(xmake_function: XmakeFunction) -> Option<SemanticTokenType> {
+    match xmake_function {
+        XmakeFunction::Target => Some(XMAKE_TARGET_TOKEN_TYPE),
+        XmakeFunction::Package => Some(XMAKE_PACKAGE_TOKEN_TYPE),
+        XmakeFunction::Rule => Some(XMAKE_RULE_TOKEN_TYPE),
+        XmakeFunction::Option => Some(XMAKE_OPTION_TOKEN_TYPE),
+        XmakeFunction::Task => Some(XMAKE_TASK_TOKEN_TYPE),
+        XmakeFunction::Toolchain => Some(XMAKE_TOOLCHAIN_TOKEN_TYPE),
+        _ => None,
+    }
+}
+
+fn is_scope_end(xmake_function: XmakeFunction) -> bool {
+    matches!(
+        xmake_function,
+        XmakeFunction::EndTarget
+            | XmakeFunction::EndPackage
+            | XmakeFunction::EndRule
+            | XmakeFunction::EndOption
+            | XmakeFunction::EndTask
+            | XmakeFunction::EndToolchain
+    )
+}
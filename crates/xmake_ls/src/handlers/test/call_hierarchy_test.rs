@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_parser::LuaAstNode;
+    use googletest::prelude::*;
+    use rowan::TextSize;
+
+    use crate::handlers::{
+        call_hierarchy::{
+            XmakeHierarchyItemData, build_xmake_hierarchy_item, build_xmake_incoming_hierarchy,
+            build_xmake_outgoing_hierarchy,
+        },
+        test_lib::ProviderVirtualWorkspace,
+    };
+
+    #[gtest]
+    fn test_xmake_target_hierarchy() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let content = r#"
+            target("core")
+                set_kind("static")
+            target("net")
+                add_deps("core")
+            target("app")
+                add_deps("net", "core", "zlib")
+        "#;
+        let file_id = ws.def_file("xmake.lua", content);
+        let semantic_model = ws
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .or_fail()?;
+        let offset = TextSize::from((content.find("\"core\"").or_fail()? + 1) as u32);
+        let token = semantic_model
+            .get_root()
+            .syntax()
+            .token_at_offset(offset)
+            .right_biased()
+            .or_fail()?;
+
+        let item = build_xmake_hierarchy_item(&semantic_model, token).or_fail()?;
+        verify_eq!(item.name.as_str(), "core")?;
+        verify_eq!(item.detail.as_deref(), Some("static"))?;
+        let data = serde_json::from_value::<XmakeHierarchyItemData>(item.data.or_fail()?)?;
+        verify_eq!(
+            data.clone(),
+            XmakeHierarchyItemData::Target {
+                name: "core".to_string()
+            }
+        )?;
+
+        let db = ws.analysis.compilation.get_db();
+        let incoming = build_xmake_incoming_hierarchy(db, data).or_fail()?;
+        let dependents = incoming
+            .iter()
+            .map(|call| (call.from.name.as_str(), call.from_ranges.len()))
+            .collect::<Vec<_>>();
+        verify_eq!(dependents, vec![("net", 1), ("app", 1)])?;
+
+        let outgoing = build_xmake_outgoing_hierarchy(
+            db,
+            XmakeHierarchyItemData::Target {
+                name: "app".to_string(),
+            },
+        )
+        .or_fail()?;
+        // `zlib` is not a target of the workspace
+        let deps = outgoing
+            .iter()
+            .map(|call| call.to.name.as_str())
+            .collect::<Vec<_>>();
+        verify_eq!(deps, vec!["net", "core"])?;
+
+        Ok(())
+    }
+}
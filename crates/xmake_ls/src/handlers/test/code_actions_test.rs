@@ -88,3 +88,311 @@
 //         Ok(())
 //     }
 // }
+
+#[cfg(test)]
+mod xmake_tests {
+    use googletest::prelude::*;
+    use lsp_types::{
+        ClientCapabilities, CodeActionOrCommand, DocumentChangeOperation, DocumentChanges, OneOf,
+        Position, Range,
+    };
+
+    use xmake_code_analysis::{DiagnosticCode, Emmyrc};
+
+    use crate::handlers::{
+        code_actions::code_action,
+        test_lib::{
+            ProviderVirtualWorkspace, VirtualCodeAction, check, get_workspace_edit_capabilities,
+        },
+    };
+
+    #[gtest]
+    fn test_fix_format() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc
+            .diagnostics
+            .enables
+            .push(DiagnosticCode::CodeStyleCheck);
+        ws.analysis.update_config(emmyrc.into());
+        check!(ws.check_code_action(
+            "local a   =   1\nreturn a\n",
+            vec![
+                VirtualCodeAction {
+                    title: "Fix formatting here".to_string()
+                },
+                VirtualCodeAction {
+                    title: "Disable current line diagnostic (code-style-check)".to_string()
+                },
+                VirtualCodeAction {
+                    title: "Disable all diagnostics in current file (code-style-check)".to_string()
+                },
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_convert_to_block() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+            target("a<??>pp", {
+                kind = "binary",
+                files = {"src/*.c", "main.c"},
+            })
+            "#,
+            "Convert to block style target",
+            r#"
+            target("app")
+                set_kind("binary")
+                add_files("src/*.c", "main.c")
+            target_end()
+            "#,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_convert_to_table() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+            target("a<??>pp")
+                set_kind("binary")
+                add_files("src/*.c")
+                add_files("main.c")
+            target_end()
+            "#,
+            "Convert to table style target",
+            r#"
+            target("app", {
+                kind = "binary",
+                files = {"src/*.c", "main.c"},
+            })
+            "#,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_extract_common_settings() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let block = r#"
+            <??>target("a")
+                add_defines("X")
+                add_files("a.c")
+            target("b")
+                add_files("b.c")
+                add_defines("X")<??>
+        "#;
+        check!(ws.check_refactor_action(
+            block,
+            "Extract common settings into rule common",
+            r#"
+            rule("common")
+                on_load(function (target)
+                    target:add("defines", "X")
+                end)
+            rule_end()
+
+            target("a")
+                add_rules("common")
+                add_files("a.c")
+            target("b")
+                add_files("b.c")
+                add_rules("common")
+        "#,
+        ));
+        check!(ws.check_refactor_action(
+            block,
+            "Extract common settings into a loop",
+            r#"
+            target("a")
+                add_files("a.c")
+            target("b")
+                add_files("b.c")
+
+            for _, name in ipairs({"a", "b"}) do
+                target(name)
+                    add_defines("X")
+                target_end()
+            end
+        "#,
+        ));
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[gtest]
+    fn test_wrap_is_plat() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+            target("a")
+                <??>add_defines("X")
+                add_files("a.c")<??>
+            "#,
+            "Wrap in is_plat(linux) check",
+            r#"
+            target("a")
+                if is_plat("linux") then
+                    add_defines("X")
+                    add_files("a.c")
+                end
+            "#,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_move_target() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let block = r#"
+            target("co<??>re")
+                add_files("src/*.c", "$(builddir)/gen.c")
+            target_end()
+            target("app")
+            "#;
+        check!(ws.check_refactor_action(
+            block,
+            "Move target to core/xmake.lua",
+            r#"
+            includes("core")
+            target("app")
+            "#,
+        ));
+
+        let file_id = ws.def(&block.replace("<??>", ""));
+        let position = Position::new(1, 22);
+        let actions = code_action(
+            &ws.analysis,
+            file_id,
+            vec![],
+            Range::new(position, position),
+            &get_workspace_edit_capabilities(),
+        )
+        .or_fail()?;
+        let new_content = actions
+            .into_iter()
+            .filter_map(|action| match action {
+                lsp_types::CodeActionOrCommand::CodeAction(action) => action.edit,
+                _ => None,
+            })
+            .filter_map(|edit| match edit.document_changes? {
+                DocumentChanges::Operations(operations) => Some(operations),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|operation| match operation {
+                DocumentChangeOperation::Edit(edit)
+                    if edit.text_document.uri.as_str().ends_with("core/xmake.lua") =>
+                {
+                    match edit.edits.into_iter().next()? {
+                        OneOf::Left(edit) => Some(edit.new_text),
+                        OneOf::Right(edit) => Some(edit.text_edit.new_text),
+                    }
+                }
+                _ => None,
+            })
+            .or_fail()?;
+        verify_eq!(
+            new_content,
+            "target(\"core\")\n    add_files(\"../src/*.c\", \"$(builddir)/gen.c\")\ntarget_end()\n"
+        )?;
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_convert_keeps_name_text() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_refactor_action(
+            r#"
+            target('a<??>pp')
+                set_kind("binary")
+            target_end()
+            "#,
+            "Convert to table style target",
+            r#"
+            target('app', {
+                kind = "binary",
+            })
+            "#,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_move_target_long_string_path() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"target("core")
+    add_files([[src/*.c]], [==[
+main.c]==])
+target_end()
+"#,
+        );
+        let position = Position::new(0, 9);
+        let actions = code_action(
+            &ws.analysis,
+            file_id,
+            vec![],
+            Range::new(position, position),
+            &get_workspace_edit_capabilities(),
+        )
+        .or_fail()?;
+        let new_content = actions
+            .into_iter()
+            .filter_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => action.edit?.document_changes,
+                _ => None,
+            })
+            .filter_map(|document_changes| match document_changes {
+                DocumentChanges::Operations(operations) => Some(operations),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|operation| match operation {
+                DocumentChangeOperation::Edit(edit)
+                    if edit.text_document.uri.as_str().ends_with("core/xmake.lua") =>
+                {
+                    match edit.edits.into_iter().next()? {
+                        OneOf::Left(edit) => Some(edit.new_text),
+                        OneOf::Right(edit) => Some(edit.text_edit.new_text),
+                    }
+                }
+                _ => None,
+            })
+            .or_fail()?;
+        verify_eq!(
+            new_content,
+            "target(\"core\")\n    add_files([[../src/*.c]], [==[\n../main.c]==])\ntarget_end()\n"
+        )?;
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_move_target_needs_create_file() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"target("core")
+    add_files("src/*.c")
+target_end()
+"#,
+        );
+        let position = Position::new(0, 9);
+        let actions = code_action(
+            &ws.analysis,
+            file_id,
+            vec![],
+            Range::new(position, position),
+            &ClientCapabilities::default(),
+        )
+        .unwrap_or_default();
+        let has_move = actions.iter().any(|action| {
+            matches!(action, CodeActionOrCommand::CodeAction(action) if action.title.starts_with("Move target"))
+        });
+        verify_that!(has_move, eq(false))?;
+        Ok(())
+    }
+}
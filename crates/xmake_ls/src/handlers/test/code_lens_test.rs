@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualCodeLens, check};
+
+    fn target_lens(title: &str, command: &str, line: u32) -> VirtualCodeLens {
+        VirtualCodeLens {
+            title: title.to_string(),
+            command: command.to_string(),
+            line,
+        }
+    }
+
+    #[gtest]
+    fn test_target_code_lens() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_code_lens(
+            r#"
+                package("zlib")
+                target("core")
+                    set_kind("static")
+                target("app")
+            "#,
+            vec![
+                target_lens("▶ Run", "xmake.runTarget", 2),
+                target_lens("Build", "xmake.buildTarget", 2),
+                target_lens("Clean", "xmake.cleanTarget", 2),
+                target_lens("Rebuild", "xmake.rebuildTarget", 2),
+                target_lens("▶ Run", "xmake.runTarget", 4),
+                target_lens("Build", "xmake.buildTarget", 4),
+                target_lens("Clean", "xmake.cleanTarget", 4),
+                target_lens("Rebuild", "xmake.rebuildTarget", 4),
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_task_code_lens() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_code_lens(
+            r#"
+                task("hello")
+                    on_run(function ()
+                        print("hello")
+                    end)
+                    set_menu {
+                        usage = "xmake hello [options]",
+                        options = {
+                            {"v", "verbose", "k", nil, "Print more"}
+                        }
+                    }
+                task("internal")
+                    on_run("main")
+            "#,
+            vec![target_lens("▶ Run task", "xmake.runTask", 1)]
+        ));
+        Ok(())
+    }
+}
@@ -1353,4 +1353,196 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_hide_api_newer_than_xmakever() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@since 2.8.2
+            function set_exceptions(type) end
+
+            function set_extension(ext) end
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+            set_xmakever("2.8.0")
+            set_ext<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: "set_extension".to_string(),
+                kind: CompletionItemKind::FUNCTION,
+                label_detail: Some("(ext)".to_string()),
+            }],
+        ));
+        check!(ws.check_completion(
+            r#"
+            set_xmakever("2.9.0")
+            set_ext<??>
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "set_exceptions".to_string(),
+                    kind: CompletionItemKind::FUNCTION,
+                    label_detail: Some("(type)".to_string()),
+                },
+                VirtualCompletionItem {
+                    label: "set_extension".to_string(),
+                    kind: CompletionItemKind::FUNCTION,
+                    label_detail: Some("(ext)".to_string()),
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_toolchain_completion() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+            toolchain("mingw")
+                set_description("Minimalist GNU for Windows")
+            toolchain_end()
+            toolchain("gcc")
+            toolchain_end()
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+            target("app")
+                set_toolchains("<??>")
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "gcc".to_string(),
+                    kind: CompletionItemKind::CONSTANT,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "mingw".to_string(),
+                    kind: CompletionItemKind::CONSTANT,
+                    ..Default::default()
+                },
+            ],
+        ));
+        check!(ws.check_completion(
+            r#"
+            if is_config("toolchain", "<??>") then
+            end
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "gcc".to_string(),
+                    kind: CompletionItemKind::CONSTANT,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "mingw".to_string(),
+                    kind: CompletionItemKind::CONSTANT,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_target_attr_deps_completion() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_completion(
+            r#"
+            target("core", {kind = "static"})
+            target("app", {deps = {"<??>"}})
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "app".to_string(),
+                    kind: CompletionItemKind::CONSTANT,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "core".to_string(),
+                    kind: CompletionItemKind::CONSTANT,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_target_attr_field_completion() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@param target string
+            ---@param attr TargetAttr
+            function target(target, attr) end
+
+            ---@scope target
+            function set_kind(kind) end
+
+            ---@scope target
+            function add_files(file, ...) end
+
+            ---@scope target
+            function on_build(func) end
+
+            ---@scope option
+            function set_showmenu(show) end
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+            target("app", {kind = "binary", <??>})
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "files = ".to_string(),
+                    kind: CompletionItemKind::PROPERTY,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "on_build = ".to_string(),
+                    kind: CompletionItemKind::PROPERTY,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_task_option_completion() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_completion(
+            r#"
+            task("hello")
+                set_menu {
+                    options = {
+                        {'k', "key", "kv", nil, "The key"},
+                        {nil, "files", "vs", nil, "The files"}
+                    }
+                }
+                on_run(function ()
+                    print(option.get("<??>"))
+                end)
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "key".to_string(),
+                    kind: CompletionItemKind::PROPERTY,
+                    label_detail: Some(" -k KEY".to_string()),
+                },
+                VirtualCompletionItem {
+                    label: "files".to_string(),
+                    kind: CompletionItemKind::PROPERTY,
+                    label_detail: Some(" files ...".to_string()),
+                },
+            ],
+        ));
+        Ok(())
+    }
 }
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use xmake_code_analysis::WorkspaceId;
+
+    use crate::handlers::{
+        dependency_graph::build_dependency_graph, test_lib::ProviderVirtualWorkspace,
+    };
+
+    #[gtest]
+    fn test_dependency_graph() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "xmake.lua",
+            r#"
+                rule("gen")
+                target("core")
+                    set_kind("static")
+                    add_packages("zlib")
+                target("app")
+                    add_deps("core")
+                    add_rules("gen", "mode.debug")
+                    add_packages("zlib")
+            "#,
+        );
+        let uri = ws.virtual_url_generator.new_uri("xmake.lua");
+        let result = build_dependency_graph(&ws.analysis, Some(&uri)).or_fail()?;
+
+        let declared = result
+            .graph
+            .nodes
+            .iter()
+            .filter(|node| node.location.is_some())
+            .map(|node| node.id.as_str())
+            .collect::<Vec<_>>();
+        verify_eq!(declared, vec!["rule:gen", "target:app", "target:core"])?;
+        verify_eq!(
+            result.dot,
+            r#"digraph xmake {
+    rankdir=LR;
+    "package:zlib" [label="zlib", shape=ellipse];
+    "rule:gen" [label="gen", shape=diamond];
+    "rule:mode.debug" [label="mode.debug", shape=diamond];
+    "target:app" [label="app", shape=box];
+    "target:core" [label="core", shape=box];
+    "target:app" -> "package:zlib" [style=dashed];
+    "target:app" -> "rule:gen" [style=dotted];
+    "target:app" -> "rule:mode.debug" [style=dotted];
+    "target:app" -> "target:core";
+    "target:core" -> "package:zlib" [style=dashed];
+}
+"#
+        )?;
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_dependency_graph_skips_builtin_files() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_main_workspace();
+        let base = ws.virtual_url_generator.base.clone();
+        ws.analysis
+            .add_custom_workspace(WorkspaceId::BUILTIN_INCLUDE, base.join("rules"));
+        ws.analysis
+            .add_custom_workspace(WorkspaceId::BUILTIN_TOOLCHAIN, base.join("toolchains"));
+        ws.def_file(
+            "rules/mode/xmake.lua",
+            r#"
+                rule("mode.debug")
+                target("builtin")
+                    add_rules("mode.debug")
+            "#,
+        );
+        ws.def_file(
+            "toolchains/gcc/xmake.lua",
+            r#"
+                toolchain("gcc")
+                toolchain_end()
+            "#,
+        );
+        ws.def_file(
+            "xmake.lua",
+            r#"
+                target("app")
+                    add_rules("mode.debug")
+            "#,
+        );
+        let result = build_dependency_graph(&ws.analysis, None).or_fail()?;
+
+        let nodes = result
+            .graph
+            .nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node.location.is_some()))
+            .collect::<Vec<_>>();
+        verify_eq!(
+            nodes,
+            vec![("rule:mode.debug", false), ("target:app", true)]
+        )
+    }
+}
@@ -0,0 +1,243 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+    use googletest::prelude::*;
+    use lsp_server::{Connection, Message};
+    use lsp_types::{
+        ClientCapabilities, Diagnostic, DiagnosticClientCapabilities,
+        DiagnosticWorkspaceClientCapabilities, PublishDiagnosticsParams,
+        TextDocumentClientCapabilities, Uri, WorkspaceClientCapabilities,
+    };
+    use tokio::sync::RwLock;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{
+        context::{ClientProxy, DiagnosticMode, FileDiagnostic, StatusBar},
+        handlers::test_lib::ProviderVirtualWorkspace,
+    };
+
+    #[gtest]
+    fn test_diagnostic_mode() {
+        expect_eq!(
+            DiagnosticMode::from_client_capabilities(&ClientCapabilities::default()),
+            DiagnosticMode::Push
+        );
+
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                diagnostic: Some(DiagnosticClientCapabilities::default()),
+                ..Default::default()
+            }),
+            workspace: Some(WorkspaceClientCapabilities {
+                diagnostic: Some(DiagnosticWorkspaceClientCapabilities {
+                    refresh_support: Some(true),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        expect_eq!(
+            DiagnosticMode::from_client_capabilities(&client_capabilities),
+            DiagnosticMode::Pull {
+                refresh_support: true
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pull_result_id() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("local a = b\nreturn a\n");
+        let uri = ws.analysis.get_uri(file_id).or_fail()?;
+
+        let (connection, _client_connection) = Connection::memory();
+        let client = Arc::new(ClientProxy::new(connection));
+        let analysis = Arc::new(RwLock::new(ws.analysis));
+        let file_diagnostic = FileDiagnostic::new(
+            analysis.clone(),
+            Arc::new(StatusBar::new(client.clone())),
+            client,
+            DiagnosticMode::Pull {
+                refresh_support: false,
+            },
+        );
+
+        let (result_id, diagnostics) = file_diagnostic
+            .pull_file_diagnostics(file_id, CancellationToken::new())
+            .await
+            .or_fail()?;
+        verify_that!(diagnostics, not(is_empty()))?;
+        let (cached_result_id, _) = file_diagnostic
+            .pull_file_diagnostics(file_id, CancellationToken::new())
+            .await
+            .or_fail()?;
+        verify_eq!(&cached_result_id, &result_id)?;
+
+        analysis
+            .write()
+            .await
+            .update_file_by_uri(&uri, Some("local b = 1\nlocal a = b\nreturn a\n".into()));
+        file_diagnostic.add_diagnostic_task(file_id, 0).await;
+        let (new_result_id, diagnostics) = file_diagnostic
+            .pull_file_diagnostics(file_id, CancellationToken::new())
+            .await
+            .or_fail()?;
+        verify_that!(diagnostics, is_empty())?;
+        verify_ne!(new_result_id, result_id)
+    }
+
+    fn take_published(connection: &Connection) -> Vec<PublishDiagnosticsParams> {
+        connection
+            .receiver
+            .try_iter()
+            .filter_map(|message| match message {
+                Message::Notification(notification)
+                    if notification.method == "textDocument/publishDiagnostics" =>
+                {
+                    serde_json::from_value(notification.params).ok()
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_build_diagnostics_clear_stale_uris() -> Result<()> {
+        let ws = ProviderVirtualWorkspace::new();
+        let (connection, client_connection) = Connection::memory();
+        let client = Arc::new(ClientProxy::new(connection));
+        let file_diagnostic = FileDiagnostic::new(
+            Arc::new(RwLock::new(ws.analysis)),
+            Arc::new(StatusBar::new(client.clone())),
+            client,
+            DiagnosticMode::Push,
+        );
+        let main_uri = Uri::from_str("file:///project/src/main.cpp").unwrap();
+        let lib_uri = Uri::from_str("file:///project/src/lib.cpp").unwrap();
+        let diagnostic = Diagnostic {
+            message: "expected ';'".to_string(),
+            ..Default::default()
+        };
+
+        file_diagnostic
+            .set_build_diagnostics(HashMap::from([
+                (main_uri.to_string(), vec![diagnostic.clone()]),
+                (lib_uri.to_string(), vec![diagnostic.clone()]),
+            ]))
+            .await;
+        let published = take_published(&client_connection);
+        verify_eq!(published.len(), 2)?;
+        verify_that!(
+            published
+                .iter()
+                .map(|params| params.diagnostics.len())
+                .collect::<Vec<_>>(),
+            each(eq(&1))
+        )?;
+
+        // the next build only fails in main.cpp, lib.cpp is cleared
+        file_diagnostic
+            .set_build_diagnostics(HashMap::from([(main_uri.to_string(), vec![diagnostic])]))
+            .await;
+        let published = take_published(&client_connection)
+            .into_iter()
+            .map(|params| (params.uri.to_string(), params.diagnostics.len()))
+            .collect::<HashMap<_, _>>();
+        verify_eq!(
+            published,
+            HashMap::from([(main_uri.to_string(), 1), (lib_uri.to_string(), 0)])
+        )
+    }
+
+    #[tokio::test]
+    async fn test_pull_reports_invalidated_per_file() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let edited = ws.def("local a = b\nreturn a\n");
+        let other = ws.def("local c = d\nreturn c\n");
+        let other_uri = ws.analysis.get_uri(other).or_fail()?;
+
+        let (connection, _client_connection) = Connection::memory();
+        let client = Arc::new(ClientProxy::new(connection));
+        let analysis = Arc::new(RwLock::new(ws.analysis));
+        let file_diagnostic = FileDiagnostic::new(
+            analysis.clone(),
+            Arc::new(StatusBar::new(client.clone())),
+            client,
+            DiagnosticMode::Pull {
+                refresh_support: false,
+            },
+        );
+        let (other_result_id, _) = file_diagnostic
+            .pull_file_diagnostics(other, CancellationToken::new())
+            .await
+            .or_fail()?;
+
+        // an edit of another file keeps the cached report
+        analysis.write().await.update_file_by_uri(
+            &other_uri,
+            Some("local d = 1\nlocal c = d\nreturn c\n".into()),
+        );
+        file_diagnostic.add_diagnostic_task(edited, 0).await;
+        let (cached_result_id, _) = file_diagnostic
+            .pull_file_diagnostics(other, CancellationToken::new())
+            .await
+            .or_fail()?;
+        verify_eq!(&cached_result_id, &other_result_id)?;
+
+        file_diagnostic.add_diagnostic_task(other, 0).await;
+        let (new_result_id, diagnostics) = file_diagnostic
+            .pull_file_diagnostics(other, CancellationToken::new())
+            .await
+            .or_fail()?;
+        verify_that!(diagnostics, is_empty())?;
+        verify_ne!(new_result_id, other_result_id)
+    }
+
+    #[tokio::test]
+    async fn test_pull_mode_publishes_build_diagnostics_of_sources() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("return 1\n");
+        let script_uri = ws.analysis.get_uri(file_id).or_fail()?;
+        let (connection, client_connection) = Connection::memory();
+        let client = Arc::new(ClientProxy::new(connection));
+        let file_diagnostic = FileDiagnostic::new(
+            Arc::new(RwLock::new(ws.analysis)),
+            Arc::new(StatusBar::new(client.clone())),
+            client,
+            DiagnosticMode::Pull {
+                refresh_support: false,
+            },
+        );
+        let source_uri = Uri::from_str("file:///project/src/main.cpp").unwrap();
+        let diagnostic = Diagnostic {
+            message: "expected ';'".to_string(),
+            ..Default::default()
+        };
+
+        file_diagnostic
+            .set_build_diagnostics(HashMap::from([
+                (source_uri.to_string(), vec![diagnostic.clone()]),
+                (script_uri.to_string(), vec![diagnostic]),
+            ]))
+            .await;
+        // the script is pulled, the source file has no FileId and is only published
+        let published = take_published(&client_connection)
+            .into_iter()
+            .map(|params| (params.uri.to_string(), params.diagnostics.len()))
+            .collect::<HashMap<_, _>>();
+        verify_eq!(published, HashMap::from([(source_uri.to_string(), 1)]))?;
+        let (_, diagnostics) = file_diagnostic
+            .pull_file_diagnostics(file_id, CancellationToken::new())
+            .await
+            .or_fail()?;
+        verify_that!(diagnostics, len(eq(1)))?;
+
+        file_diagnostic.set_build_diagnostics(HashMap::new()).await;
+        let published = take_published(&client_connection)
+            .into_iter()
+            .map(|params| (params.uri.to_string(), params.diagnostics.len()))
+            .collect::<HashMap<_, _>>();
+        verify_eq!(published, HashMap::from([(source_uri.to_string(), 0)]))
+    }
+}
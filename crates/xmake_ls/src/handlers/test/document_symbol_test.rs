@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use lsp_types::SymbolKind;
+
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualDocumentSymbol, check};
+
+    fn symbol(
+        name: &str,
+        detail: Option<&str>,
+        kind: SymbolKind,
+        children: Vec<VirtualDocumentSymbol>,
+    ) -> VirtualDocumentSymbol {
+        VirtualDocumentSymbol {
+            name: name.to_string(),
+            detail: detail.map(|detail| detail.to_string()),
+            kind,
+            children,
+        }
+    }
+
+    #[gtest]
+    fn test_target_symbols() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_document_symbol(
+            r#"
+                target("core")
+                    set_kind("static")
+
+                target("app", {kind = "binary", deps = "core"})
+            "#,
+            vec![
+                symbol("core", Some("target static"), SymbolKind::MODULE, vec![]),
+                symbol(
+                    "app",
+                    Some("target binary"),
+                    SymbolKind::MODULE,
+                    vec![symbol(
+                        "table",
+                        None,
+                        SymbolKind::STRUCT,
+                        vec![
+                            symbol("kind", None, SymbolKind::FIELD, vec![]),
+                            symbol("deps", None, SymbolKind::FIELD, vec![]),
+                        ],
+                    )],
+                ),
+            ]
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use googletest::prelude::*;
+    use lsp_server::{Connection, Message};
+    use tokio::sync::RwLock;
+    use xmake_code_analysis::{WorkspaceId, XmakeTargetKind};
+    use xmake_wrapper::XmakeWrapper;
+
+    use crate::{
+        context::{ClientProxy, DiagnosticMode, FileDiagnostic, StatusBar, WorkspaceManager},
+        handlers::{
+            explorer::{build_explorer_items, get_explorer_hash},
+            test_lib::ProviderVirtualWorkspace,
+        },
+    };
+
+    #[gtest]
+    fn test_explorer_items() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_main_workspace();
+        ws.def_file(
+            "xmake.lua",
+            r#"
+                option("with_ssl")
+                target("core")
+                    set_kind("static")
+                    add_packages("zlib")
+                target("app", {kind = "binary", deps = {"core"}, files = "src/*.cpp"})
+                target("test")
+                    add_options("with_ssl")
+            "#,
+        );
+        ws.def_file(
+            "other/xmake.lua",
+            r#"
+                target("tool")
+                    set_kind("binary")
+            "#,
+        );
+        let uri = ws.virtual_url_generator.new_uri("xmake.lua");
+
+        let targets = build_explorer_items(&ws.analysis, Some(&uri), XmakeTargetKind::Target);
+        let names = targets
+            .iter()
+            .map(|item| item.name.as_str())
+            .collect::<Vec<_>>();
+        verify_eq!(names, vec!["core", "app", "test"])?;
+        let app = &targets[1];
+        verify_eq!(app.kind.as_str(), "target")?;
+        verify_eq!(app.uri.clone(), uri.clone())?;
+        verify_eq!(app.selection_range.start.line, 5)?;
+        verify_eq!(app.target_kind.as_deref(), Some("binary"))?;
+        verify_eq!(app.deps.clone(), vec!["core".to_string()])?;
+        verify_eq!(app.files.clone(), vec!["src/*.cpp".to_string()])?;
+        verify_eq!(targets[2].options.clone(), vec!["with_ssl".to_string()])?;
+        verify_eq!(targets[0].packages.clone(), vec!["zlib".to_string()])?;
+
+        let options = build_explorer_items(&ws.analysis, Some(&uri), XmakeTargetKind::Option);
+        verify_eq!(options.len(), 1)?;
+        verify_eq!(options[0].kind.as_str(), "option")?;
+
+        let all_targets = build_explorer_items(&ws.analysis, None, XmakeTargetKind::Target);
+        verify_eq!(all_targets.len(), 4)?;
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_explorer_hash() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_main_workspace();
+        ws.def_file("xmake.lua", r#"target("app")"#);
+        let uris = [ws.virtual_url_generator.new_uri("xmake.lua")];
+        let hash = get_explorer_hash(&ws.analysis, &uris);
+
+        ws.def_file("xmake.lua", r#"target("app")"#);
+        verify_eq!(get_explorer_hash(&ws.analysis, &uris), hash)?;
+
+        // moving a target does not change what the explorer shows
+        ws.def_file("xmake.lua", "\n\n-- app\ntarget(\"app\")");
+        verify_eq!(get_explorer_hash(&ws.analysis, &uris), hash)?;
+
+        // other files are not compared
+        ws.def_file("other/xmake.lua", r#"target("tool")"#);
+        verify_eq!(get_explorer_hash(&ws.analysis, &uris), hash)?;
+
+        ws.def_file("xmake.lua", r#"target("app") add_deps("core")"#);
+        verify_ne!(get_explorer_hash(&ws.analysis, &uris), hash)?;
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_explorer_skips_builtin_toolchains() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_main_workspace();
+        let toolchains_root = ws.virtual_url_generator.base.join("toolchains");
+        ws.analysis
+            .add_custom_workspace(WorkspaceId::BUILTIN_TOOLCHAIN, toolchains_root);
+        ws.def_file(
+            "toolchains/gcc/xmake.lua",
+            r#"
+                toolchain("gcc")
+                toolchain_end()
+            "#,
+        );
+        ws.def_file(
+            "xmake.lua",
+            r#"
+                toolchain("mygcc")
+                toolchain_end()
+            "#,
+        );
+
+        let toolchains = build_explorer_items(&ws.analysis, None, XmakeTargetKind::Toolchain);
+        let names = toolchains
+            .iter()
+            .map(|item| item.name.as_str())
+            .collect::<Vec<_>>();
+        verify_eq!(names, vec!["mygcc"])?;
+
+        let builtin_uris = [ws.virtual_url_generator.new_uri("toolchains/gcc/xmake.lua")];
+        let hash = get_explorer_hash(&ws.analysis, &builtin_uris);
+        ws.def_file(
+            "toolchains/gcc/xmake.lua",
+            r#"
+                toolchain("gcc")
+                    set_kind("standalone")
+                toolchain_end()
+            "#,
+        );
+        verify_eq!(get_explorer_hash(&ws.analysis, &builtin_uris), hash)
+    }
+
+    #[tokio::test]
+    async fn test_explorer_changed_debounced() -> Result<()> {
+        let ws = ProviderVirtualWorkspace::new();
+        let (connection, client_connection) = Connection::memory();
+        let client = Arc::new(ClientProxy::new(connection));
+        let analysis = Arc::new(RwLock::new(ws.analysis));
+        let status_bar = Arc::new(StatusBar::new(client.clone()));
+        let file_diagnostic = Arc::new(FileDiagnostic::new(
+            analysis.clone(),
+            status_bar.clone(),
+            client.clone(),
+            DiagnosticMode::Push,
+        ));
+        let workspace_manager = WorkspaceManager::new(
+            analysis,
+            client,
+            status_bar,
+            file_diagnostic,
+            Arc::new(XmakeWrapper::new()),
+        );
+
+        for _ in 0..3 {
+            workspace_manager
+                .notify_explorer_changed(Duration::from_millis(20))
+                .await;
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let notifications = client_connection
+            .receiver
+            .try_iter()
+            .filter(|message| {
+                matches!(message, Message::Notification(notification)
+                    if notification.method == "xmake/explorerChanged")
+            })
+            .count();
+        verify_eq!(notifications, 1)
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualFoldingRange, check};
+
+    fn fold(start_line: u32, end_line: u32, collapsed_text: &str) -> VirtualFoldingRange {
+        VirtualFoldingRange {
+            start_line,
+            end_line,
+            collapsed_text: Some(collapsed_text.to_string()),
+        }
+    }
+
+    #[gtest]
+    fn test_implicit_end_target_fold() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_folding_range(
+            r#"
+                target("core")
+                    set_kind("static")
+                    add_files("src/*.c")
+
+                target("app")
+                    set_kind("binary")
+            "#,
+            vec![fold(1, 3, " .. "), fold(5, 6, " .. ")]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_explicit_end_target_fold() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_folding_range(
+            r#"
+                package("zlib")
+                    add_urls("https://zlib.net/zlib-$(version).tar.gz")
+                    add_versions("1.3.1", "9a93b2b7dfdac77ceba5a558a580e74667dd6fede4585b91eefb60f03b72df23")
+                package_end()
+            "#,
+            vec![fold(1, 3, " .. ")]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_platform_region_fold() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_folding_range(
+            r#"
+                if is_plat("windows") then
+                    add_defines("WIN")
+                    add_links("ws2_32")
+                elseif is_plat("linux") and not is_arch("arm64") then
+                    add_defines("LINUX")
+                elseif is_mode("debug") then
+                    add_defines("DEBUG")
+                    set_symbols("debug")
+                elseif has_config("ssl") then
+                    add_defines("SSL")
+                else
+                    add_defines("OTHER")
+                end
+            "#,
+            vec![
+                fold(1, 3, r#"is_plat("windows")"#),
+                fold(4, 5, r#"is_plat("linux") and not is_arch("arm64")"#),
+                fold(6, 8, r#"is_mode("debug")"#),
+                fold(9, 10, " .. "),
+                fold(11, 12, " .. "),
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_platform_region_fold_disabled() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.folding.platform_regions = false;
+        ws.update_emmyrc(emmyrc);
+        check!(ws.check_folding_range(
+            r#"
+                if is_plat("windows") then
+                    add_defines("WIN")
+                elseif is_plat("linux") then
+                    add_defines("LINUX")
+                end
+            "#,
+            vec![fold(1, 2, " .. "), fold(3, 4, " .. ")]
+        ));
+        Ok(())
+    }
+}
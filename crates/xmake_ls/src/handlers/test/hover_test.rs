@@ -413,4 +413,119 @@ mod tests {
 
         Ok(())
     }
+
+    #[gtest]
+    fn test_xmake_target_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "core.lua",
+            r#"
+                target("core")
+                    set_kind("static")
+                    add_files("src/*.c", "src/*.cpp")
+                    add_defines("CORE")
+                    add_packages("zlib")
+                    on_load(function (target)
+                        add_deps("not_a_dep")
+                    end)
+            "#,
+        );
+        let core_uri = ws.virtual_url_generator.new_uri("core.lua");
+        check!(ws.check_hover(
+            r#"
+                target("app")
+                    add_deps("co<??>re")
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\ntarget(\"core\")\n```\n\n---\n\n**kind**: `static`\n\n**packages**: `zlib`\n\n**files**: `src/*.c`, `src/*.cpp`\n\n**defines**: `CORE`\n\n---\n\nDeclared in [core.lua:2]({}#L2)",
+                    core_uri.as_str()
+                ),
+            },
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_toolchain_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "toolchains.lua",
+            r#"
+                toolchain("gcc")
+                    set_kind("standalone")
+                    set_description("GNU Compiler Collection")
+                toolchain_end()
+            "#,
+        );
+        let uri = ws.virtual_url_generator.new_uri("toolchains.lua");
+        check!(ws.check_hover(
+            r#"
+                target("app")
+                    set_toolchains("gcc-1<??>1")
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\ntoolchain(\"gcc\")\n```\n\nGNU Compiler Collection\n\n---\n\n**kind**: `standalone`\n\n---\n\nDeclared in [toolchains.lua:2]({}#L2)",
+                    uri.as_str()
+                ),
+            },
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_target_attr_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "core.lua",
+            r#"
+                target("core", {kind = "static", files = {"src/*.c"}, packages = "zlib"})
+            "#,
+        );
+        let core_uri = ws.virtual_url_generator.new_uri("core.lua");
+        check!(ws.check_hover(
+            r#"
+                target("app", {deps = "co<??>re"})
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\ntarget(\"core\")\n```\n\n---\n\n**kind**: `static`\n\n**packages**: `zlib`\n\n**files**: `src/*.c`\n\n---\n\nDeclared in [core.lua:2]({}#L2)",
+                    core_uri.as_str()
+                ),
+            },
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_task_usage_hover() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        // the file `check_hover` defines
+        let uri = ws.virtual_url_generator.new_uri("virtual_0.lua");
+        check!(ws.check_hover(
+            r#"
+                task("hel<??>lo")
+                    set_menu {
+                        options = {
+                            {'k', "key", "kv", nil, "The key"},
+                            {nil, "force", "k"},
+                            {},
+                            {nil, "files", "vs", nil, "The files"}
+                        }
+                    }
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\ntask(\"hello\")\n```\n\n---\n\n**usage**: `xmake hello [-k KEY] [--force] [files ...]`\n\n- `-k KEY`: The key\n- `--force`\n- `files ...`: The files\n\n---\n\nDeclared in [virtual_0.lua:2]({}#L2)",
+                    uri.as_str()
+                ),
+            },
+        ));
+
+        Ok(())
+    }
 }
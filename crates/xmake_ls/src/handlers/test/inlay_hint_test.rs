@@ -371,4 +371,57 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_xmake_scope_end_and_kind() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_inlay_hint(
+            r#"
+                target("app")
+                    set_kind("binary")
+
+                target("lib")
+                    set_kind("static")
+                target_end()
+            "#,
+            vec![
+                VirtualInlayHint {
+                    label: "-- end target \"app\"".to_string(),
+                    line: 2,
+                    pos: 38,
+                    ref_file: None,
+                },
+                VirtualInlayHint {
+                    label: ": binary".to_string(),
+                    line: 1,
+                    pos: 29,
+                    ref_file: None,
+                },
+                VirtualInlayHint {
+                    label: ": static".to_string(),
+                    line: 4,
+                    pos: 29,
+                    ref_file: None,
+                },
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_xmake_hint_toggles() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.hint.scope_end_hint = false;
+        emmyrc.hint.target_kind_hint = false;
+        ws.update_emmyrc(emmyrc);
+        check!(ws.check_inlay_hint(
+            r#"
+                target("app")
+                    set_kind("binary")
+            "#,
+            vec![]
+        ));
+        Ok(())
+    }
 }
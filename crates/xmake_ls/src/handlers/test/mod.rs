@@ -1,7 +1,14 @@
+mod call_hierarchy_test;
 mod code_actions_test;
+mod code_lens_test;
 mod completion_resolve_test;
 mod completion_test;
 mod definition_test;
+mod dependency_graph_test;
+mod document_diagnostic_test;
+mod document_symbol_test;
+mod explorer_test;
+mod folding_range_test;
 mod hover_function_test;
 mod hover_test;
 mod implementation_test;
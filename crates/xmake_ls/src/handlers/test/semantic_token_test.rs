@@ -189,3 +189,158 @@ mod tests {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod xmake_tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualSemanticToken, check};
+    use googletest::prelude::*;
+    use lsp_types::{SemanticTokenModifier, SemanticTokenType};
+    use std::collections::HashSet;
+
+    fn token(
+        line: u32,
+        start: u32,
+        length: u32,
+        token_type: SemanticTokenType,
+        token_modifier: &[SemanticTokenModifier],
+    ) -> VirtualSemanticToken {
+        VirtualSemanticToken {
+            line,
+            start,
+            length,
+            token_type,
+            token_modifier: token_modifier.iter().cloned().collect::<HashSet<_>>(),
+        }
+    }
+
+    #[gtest]
+    fn test_xmake_scope_and_names() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let function = SemanticTokenType::FUNCTION;
+        let operator = SemanticTokenType::OPERATOR;
+        let target = SemanticTokenType::new("xmakeTarget");
+        let default_library = SemanticTokenModifier::DEFAULT_LIBRARY;
+        let scope = SemanticTokenModifier::new("xmakeScope");
+        let description = SemanticTokenModifier::new("xmakeDescription");
+        check!(ws.check_semantic_token(
+            r#"
+                target("app")
+                    add_deps("core")
+                target_end()
+            "#,
+            vec![
+                token(
+                    1,
+                    16,
+                    6,
+                    function.clone(),
+                    &[default_library.clone(), scope.clone()]
+                ),
+                token(1, 22, 1, operator.clone(), &[]),
+                token(
+                    1,
+                    23,
+                    5,
+                    target.clone(),
+                    &[SemanticTokenModifier::DECLARATION]
+                ),
+                token(1, 28, 1, operator.clone(), &[]),
+                token(
+                    2,
+                    20,
+                    8,
+                    function.clone(),
+                    &[default_library.clone(), description]
+                ),
+                token(2, 28, 1, operator.clone(), &[]),
+                token(2, 29, 6, target, &[]),
+                token(2, 35, 1, operator.clone(), &[]),
+                token(3, 16, 10, function, &[default_library, scope]),
+                token(3, 26, 1, operator.clone(), &[]),
+                token(3, 27, 1, operator, &[]),
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_xmake_toolchain_scope() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let function = SemanticTokenType::FUNCTION;
+        let operator = SemanticTokenType::OPERATOR;
+        let default_library = SemanticTokenModifier::DEFAULT_LIBRARY;
+        let scope = SemanticTokenModifier::new("xmakeScope");
+        check!(ws.check_semantic_token(
+            r#"
+                toolchain("mygcc")
+                toolchain_end()
+            "#,
+            vec![
+                token(
+                    1,
+                    16,
+                    9,
+                    function.clone(),
+                    &[default_library.clone(), scope.clone()]
+                ),
+                token(1, 25, 1, operator.clone(), &[]),
+                token(
+                    1,
+                    26,
+                    7,
+                    SemanticTokenType::new("xmakeToolchain"),
+                    &[SemanticTokenModifier::DECLARATION]
+                ),
+                token(1, 33, 1, operator.clone(), &[]),
+                token(2, 16, 13, function, &[default_library, scope]),
+                token(2, 29, 1, operator.clone(), &[]),
+                token(2, 30, 1, operator, &[]),
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_xmake_condition_and_script() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let function = SemanticTokenType::FUNCTION;
+        let operator = SemanticTokenType::OPERATOR;
+        let keyword = SemanticTokenType::KEYWORD;
+        let default_library = SemanticTokenModifier::DEFAULT_LIBRARY;
+        check!(ws.check_semantic_token(
+            r#"
+                is_plat("linux")
+                on_load(function () end)
+            "#,
+            vec![
+                token(
+                    1,
+                    16,
+                    7,
+                    function.clone(),
+                    &[
+                        default_library.clone(),
+                        SemanticTokenModifier::new("xmakeCondition")
+                    ]
+                ),
+                token(1, 23, 1, operator.clone(), &[]),
+                token(1, 24, 7, SemanticTokenType::STRING, &[]),
+                token(1, 31, 1, operator.clone(), &[]),
+                token(
+                    2,
+                    16,
+                    7,
+                    function,
+                    &[default_library, SemanticTokenModifier::new("xmakeScript")]
+                ),
+                token(2, 23, 1, operator.clone(), &[]),
+                token(2, 24, 8, keyword.clone(), &[]),
+                token(2, 33, 1, operator.clone(), &[]),
+                token(2, 34, 1, operator.clone(), &[]),
+                token(2, 36, 3, keyword, &[]),
+                token(2, 39, 1, operator, &[]),
+            ]
+        ));
+        Ok(())
+    }
+}
@@ -2,10 +2,12 @@ use googletest::prelude::*;
 use itertools::Itertools;
 use lsp_types::{
     ClientCapabilities, CodeActionOrCommand, CompletionItem, CompletionItemKind,
-    CompletionResponse, CompletionTriggerKind, GotoDefinitionResponse, Hover, HoverContents,
-    InlayHintLabel, Location, MarkupContent, Position, SemanticTokenModifier, SemanticTokenType,
-    SemanticTokensResult, SignatureHelpContext, SignatureHelpTriggerKind, SignatureInformation,
-    TextEdit,
+    CompletionResponse, CompletionTriggerKind, DocumentChangeOperation, DocumentChanges,
+    DocumentSymbol, GotoDefinitionResponse, Hover, HoverContents, InlayHintLabel, Location,
+    MarkupContent, OneOf, Position, Range, ResourceOperationKind, SemanticTokenModifier,
+    SemanticTokenType, SemanticTokensResult, SignatureHelpContext, SignatureHelpTriggerKind,
+    SignatureInformation, SymbolKind, TextEdit, WorkspaceClientCapabilities,
+    WorkspaceEditClientCapabilities,
 };
 use std::collections::HashSet;
 use std::{ops::Deref, sync::Arc};
@@ -16,7 +18,10 @@ use crate::{
     context::ClientId,
     handlers::{
         code_actions::code_action,
+        code_lens::code_lens,
         completion::{completion, completion_resolve},
+        document_symbol::document_symbol,
+        fold_range::folding_range,
         inlay_hint::inlay_hint,
         rename::rename,
         semantic_token::semantic_token,
@@ -39,6 +44,22 @@ macro_rules! check {
 }
 pub(crate) use check;
 
+/// Client capabilities of an editor applying document changes and creating files in workspace
+/// edits
+pub fn get_workspace_edit_capabilities() -> ClientCapabilities {
+    ClientCapabilities {
+        workspace: Some(WorkspaceClientCapabilities {
+            workspace_edit: Some(WorkspaceEditClientCapabilities {
+                document_changes: Some(true),
+                resource_operations: Some(vec![ResourceOperationKind::Create]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
 /// A virtual workspace for testing.
 #[allow(unused)]
 #[derive(Debug)]
@@ -96,6 +117,28 @@ pub struct VirtualInlayHint {
     pub ref_file: Option<String>,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct VirtualCodeLens {
+    pub title: String,
+    pub command: String,
+    pub line: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VirtualFoldingRange {
+    pub start_line: u32,
+    pub end_line: u32,
+    pub collapsed_text: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VirtualDocumentSymbol {
+    pub name: String,
+    pub detail: Option<String>,
+    pub kind: SymbolKind,
+    pub children: Vec<VirtualDocumentSymbol>,
+}
+
 #[derive(Debug)]
 pub struct VirtualCodeAction {
     pub title: String,
@@ -137,6 +180,19 @@ impl ProviderVirtualWorkspace {
         }
     }
 
+    /// The files are in the main workspace, as the ones of an opened project
+    pub fn new_with_main_workspace() -> Self {
+        let generator = VirtualUrlGenerator::new();
+        let mut analysis = XmakeAnalysis::new();
+        let base = &generator.base;
+        analysis.add_main_workspace(base.clone());
+        ProviderVirtualWorkspace {
+            virtual_url_generator: generator,
+            analysis,
+            id_counter: 0,
+        }
+    }
+
     pub fn def(&mut self, content: &str) -> FileId {
         let id = self.id_counter;
         self.id_counter += 1;
@@ -468,9 +524,15 @@ impl ProviderVirtualWorkspace {
             .diagnose_file(file_id, CancellationToken::new())
             .ok_or("failed to diagnose file")
             .or_fail()?;
-        let result = code_action(&self.analysis, file_id, result)
-            .ok_or("failed to generate code action")
-            .or_fail()?;
+        let result = code_action(
+            &self.analysis,
+            file_id,
+            result,
+            Range::default(),
+            &get_workspace_edit_capabilities(),
+        )
+        .ok_or("failed to generate code action")
+        .or_fail()?;
 
         fn get_code_action_label(response: &CodeActionOrCommand) -> String {
             match response {
@@ -488,6 +550,162 @@ impl ProviderVirtualWorkspace {
         )
     }
 
+    /// Apply the edits the code action titled `title` makes to the current file, the block
+    /// marks the cursor with `<??>` or the selection with two of them
+    pub fn check_refactor_action(
+        &mut self,
+        block_str: &str,
+        title: &str,
+        expected: &str,
+    ) -> Result<()> {
+        let mut positions = Vec::new();
+        let mut content = String::new();
+        for (i, part) in block_str.split("<??>").enumerate() {
+            if i > 0 {
+                let line = content.matches('\n').count();
+                let column = content.len() - content.rfind('\n').map_or(0, |pos| pos + 1);
+                positions.push(Position::new(line as u32, column as u32));
+            }
+            content.push_str(part);
+        }
+        let range = match positions.as_slice() {
+            [cursor] => Range::new(*cursor, *cursor),
+            [start, end] => Range::new(*start, *end),
+            _ => return Err("expected one or two <??>").or_fail(),
+        };
+
+        let file_id = self.def(&content);
+        let uri = self.analysis.get_uri(file_id).or_fail()?;
+        let result = code_action(
+            &self.analysis,
+            file_id,
+            vec![],
+            range,
+            &get_workspace_edit_capabilities(),
+        )
+        .ok_or("failed to generate code action")
+        .or_fail()?;
+        let edit = result
+            .into_iter()
+            .find_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) if action.title == title => action.edit,
+                _ => None,
+            })
+            .ok_or_else(|| format!("no code action titled {}", title))
+            .or_fail()?;
+
+        let mut edits = edit
+            .changes
+            .and_then(|mut changes| changes.remove(&uri))
+            .unwrap_or_default();
+        if let Some(DocumentChanges::Operations(operations)) = edit.document_changes {
+            for operation in operations {
+                if let DocumentChangeOperation::Edit(text_document_edit) = operation
+                    && text_document_edit.text_document.uri == uri
+                {
+                    edits.extend(text_document_edit.edits.into_iter().map(|edit| match edit {
+                        OneOf::Left(edit) => edit,
+                        OneOf::Right(annotated) => annotated.text_edit,
+                    }));
+                }
+            }
+        }
+
+        let line_starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(pos, _)| pos + 1))
+            .collect::<Vec<_>>();
+        let to_offset = |position: Position| {
+            line_starts
+                .get(position.line as usize)
+                .map_or(content.len(), |start| start + position.character as usize)
+        };
+        edits.sort_by_key(|edit| std::cmp::Reverse(to_offset(edit.range.start)));
+        for pair in edits.windows(2) {
+            if to_offset(pair[1].range.end) > to_offset(pair[0].range.start) {
+                return Err(format!("overlapping edits: {:?}", pair)).or_fail();
+            }
+        }
+        let mut new_content = content.clone();
+        for edit in edits {
+            new_content.replace_range(
+                to_offset(edit.range.start)..to_offset(edit.range.end),
+                &edit.new_text,
+            );
+        }
+
+        verify_eq!(new_content, expected.to_string())
+    }
+
+    pub fn check_code_lens(
+        &mut self,
+        block_str: &str,
+        expected: Vec<VirtualCodeLens>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let result = code_lens(&self.analysis, file_id)
+            .ok_or("failed to get code lens")
+            .or_fail()?;
+        let items = result
+            .into_iter()
+            .filter_map(|lens| {
+                let command = lens.command?;
+                Some(VirtualCodeLens {
+                    title: command.title,
+                    command: command.command,
+                    line: lens.range.start.line,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        verify_eq!(items, expected)
+    }
+
+    pub fn check_folding_range(
+        &mut self,
+        block_str: &str,
+        expected: Vec<VirtualFoldingRange>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let result = folding_range(&self.analysis, file_id, ClientId::VSCode)
+            .ok_or("failed to get folding range")
+            .or_fail()?;
+        let items = result
+            .into_iter()
+            .map(|range| VirtualFoldingRange {
+                start_line: range.start_line,
+                end_line: range.end_line,
+                collapsed_text: range.collapsed_text,
+            })
+            .sorted_by_key(|range| (range.start_line, range.end_line))
+            .collect::<Vec<_>>();
+
+        verify_eq!(items, expected)
+    }
+
+    pub fn check_document_symbol(
+        &mut self,
+        block_str: &str,
+        expected: Vec<VirtualDocumentSymbol>,
+    ) -> Result<()> {
+        fn to_virtual(symbols: Vec<DocumentSymbol>) -> Vec<VirtualDocumentSymbol> {
+            symbols
+                .into_iter()
+                .map(|symbol| VirtualDocumentSymbol {
+                    name: symbol.name,
+                    detail: symbol.detail,
+                    kind: symbol.kind,
+                    children: to_virtual(symbol.children.unwrap_or_default()),
+                })
+                .collect()
+        }
+
+        let file_id = self.def(block_str);
+        let result = document_symbol(&self.analysis, file_id)
+            .ok_or("failed to get document symbols")
+            .or_fail()?;
+        verify_eq!(to_virtual(result), expected)
+    }
+
     pub fn check_semantic_token(
         &mut self,
         block_str: &str,
@@ -5,7 +5,10 @@ use lsp_types::{
 use std::time::Duration;
 use xmake_code_analysis::uri_to_file_path;
 
-use crate::context::ServerContextSnapshot;
+use crate::{
+    context::ServerContextSnapshot,
+    handlers::explorer::{EXPLORER_CHANGED_DELAY, get_explorer_hash},
+};
 
 pub async fn on_did_open_text_document(
     context: ServerContextSnapshot,
@@ -23,18 +26,30 @@ pub async fn on_did_open_text_document(
         }
     }
 
+    let explorer_uris = [uri.clone()];
+    let explorer_hash = get_explorer_hash(&analysis, &explorer_uris);
     let file_id = analysis.update_file_by_uri(&uri, Some(text));
+    let explorer_changed = get_explorer_hash(&analysis, &explorer_uris) != explorer_hash;
     let emmyrc = analysis.get_emmyrc();
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
     if let Some(file_id) = file_id {
+        // the files including it read its declarations, their diagnostics may change too
+        let mut file_ids = vec![file_id];
+        file_ids.extend(analysis.collect_includers(&[file_id]));
         context
             .file_diagnostic()
-            .add_diagnostic_task(file_id, interval)
+            .add_files_diagnostic_task(file_ids, interval)
             .await;
     }
+    drop(analysis);
 
     let mut workspace = context.workspace_manager().write().await;
     workspace.current_open_files.insert(uri);
+    if explorer_changed {
+        workspace
+            .notify_explorer_changed(EXPLORER_CHANGED_DELAY)
+            .await;
+    }
     drop(workspace);
 
     Some(())
@@ -77,20 +92,36 @@ pub async fn on_did_change_text_document(
         }
     }
 
+    let explorer_uris = [uri.clone()];
+    let explorer_hash = get_explorer_hash(&analysis, &explorer_uris);
     let file_id = analysis.update_file_by_uri(&uri, Some(text));
+    let explorer_changed = get_explorer_hash(&analysis, &explorer_uris) != explorer_hash;
     let emmyrc = analysis.get_emmyrc();
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
+    let includers = match file_id {
+        Some(file_id) => analysis.collect_includers(&[file_id]),
+        None => Vec::new(),
+    };
     drop(analysis);
 
-    if emmyrc.workspace.enable_reindex {
+    if emmyrc.workspace.enable_reindex || explorer_changed {
         let workspace = context.workspace_manager().read().await;
-        workspace.extend_reindex_delay().await;
+        if emmyrc.workspace.enable_reindex {
+            workspace.extend_reindex_delay().await;
+        }
+        if explorer_changed {
+            workspace
+                .notify_explorer_changed(EXPLORER_CHANGED_DELAY)
+                .await;
+        }
         drop(workspace);
     }
     if let Some(file_id) = file_id {
+        let mut file_ids = vec![file_id];
+        file_ids.extend(includers);
         context
             .file_diagnostic()
-            .add_diagnostic_task(file_id, interval)
+            .add_files_diagnostic_task(file_ids, interval)
             .await;
     }
 
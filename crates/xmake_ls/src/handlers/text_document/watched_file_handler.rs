@@ -1,7 +1,15 @@
+use std::time::Duration;
+
 use lsp_types::{DidChangeWatchedFilesParams, FileChangeType, Uri};
 use xmake_code_analysis::{read_file_with_encoding, uri_to_file_path};
 
-use crate::context::ServerContextSnapshot;
+use crate::{
+    context::ServerContextSnapshot,
+    handlers::explorer::{EXPLORER_CHANGED_DELAY, get_explorer_hash},
+};
+
+/// Saving several xmake files at once regenerates `compile_commands.json` only once
+const COMPILE_COMMANDS_DELAY: u64 = 1000;
 
 pub async fn on_did_change_watched_files(
     context: ServerContextSnapshot,
@@ -9,16 +17,23 @@ pub async fn on_did_change_watched_files(
 ) -> Option<()> {
     let workspace = context.workspace_manager().read().await;
     let mut analysis = context.analysis().write().await;
+    let explorer_uris = params
+        .changes
+        .iter()
+        .map(|file_event| file_event.uri.clone())
+        .collect::<Vec<_>>();
+    let explorer_hash = get_explorer_hash(&analysis, &explorer_uris);
     let emmyrc = analysis.get_emmyrc();
     let encoding = &emmyrc.workspace.encoding;
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
     let mut watched_lua_files: Vec<(Uri, Option<String>)> = Vec::new();
-    // let
+    let mut xmake_file_changed = false;
     for file_event in params.changes.into_iter() {
         let file_type = get_file_type(&file_event.uri);
         match file_type {
             Some(WatchedFileType::XMakeLua) => {
                 if file_event.typ == FileChangeType::DELETED {
+                    xmake_file_changed |= analysis.get_file_id(&file_event.uri).is_some();
                     analysis.remove_file_by_uri(&file_event.uri);
                     // 发送空诊断消息以清除客户端显示的诊断
                     context
@@ -28,11 +43,12 @@ pub async fn on_did_change_watched_files(
                     continue;
                 }
 
-                if !workspace.current_open_files.contains(&file_event.uri) {
-                    if !workspace.is_workspace_file(&file_event.uri) {
-                        continue;
-                    }
+                if !workspace.is_workspace_file(&file_event.uri) {
+                    continue;
+                }
+                xmake_file_changed = true;
 
+                if !workspace.current_open_files.contains(&file_event.uri) {
                     collect_lua_files(
                         &mut watched_lua_files,
                         file_event.uri,
@@ -45,11 +61,24 @@ pub async fn on_did_change_watched_files(
         }
     }
 
-    let file_ids = analysis.update_files_by_uri(watched_lua_files);
+    let mut file_ids = analysis.update_files_by_uri(watched_lua_files);
+    file_ids.extend(analysis.collect_includers(&file_ids));
+    let explorer_changed = get_explorer_hash(&analysis, &explorer_uris) != explorer_hash;
     context
         .file_diagnostic()
         .add_files_diagnostic_task(file_ids, interval)
         .await;
+    drop(analysis);
+    if explorer_changed {
+        workspace
+            .notify_explorer_changed(EXPLORER_CHANGED_DELAY)
+            .await;
+    }
+    if xmake_file_changed {
+        workspace
+            .update_compile_commands(Duration::from_millis(COMPILE_COMMANDS_DELAY))
+            .await;
+    }
 
     Some(())
 }
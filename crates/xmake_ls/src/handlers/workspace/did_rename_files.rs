@@ -16,7 +16,13 @@ use xmake_code_analysis::{
     read_file_with_encoding, uri_to_file_path,
 };
 
-use crate::{context::ServerContextSnapshot, handlers::ClientConfig};
+use crate::{
+    context::ServerContextSnapshot,
+    handlers::{
+        ClientConfig,
+        explorer::{EXPLORER_CHANGED_DELAY, get_explorer_hash},
+    },
+};
 
 pub async fn on_did_rename_files_handler(
     context: ServerContextSnapshot,
@@ -56,6 +62,11 @@ pub async fn on_did_rename_files_handler(
         // 更新
         let mut analysis = context.analysis().write().await;
         let encoding = &analysis.get_emmyrc().workspace.encoding;
+        let explorer_uris = all_renames
+            .iter()
+            .flat_map(|rename| [rename.old_uri.clone(), rename.new_uri.clone()])
+            .collect::<Vec<_>>();
+        let explorer_hash = get_explorer_hash(&analysis, &explorer_uris);
         for rename in all_renames.iter() {
             analysis.remove_file_by_uri(&rename.old_uri);
             if let Some(new_path) = uri_to_file_path(&rename.new_uri) {
@@ -64,7 +75,16 @@ pub async fn on_did_rename_files_handler(
                 }
             }
         }
+        let explorer_changed = get_explorer_hash(&analysis, &explorer_uris) != explorer_hash;
         drop(analysis);
+        if explorer_changed {
+            context
+                .workspace_manager()
+                .read()
+                .await
+                .notify_explorer_changed(EXPLORER_CHANGED_DELAY)
+                .await;
+        }
 
         let analysis = context.analysis().read().await;
         if let Some(changes) = try_modify_require_path(&analysis.compilation, &all_renames) {
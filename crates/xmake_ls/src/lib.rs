@@ -3,6 +3,7 @@ mod context;
 mod handlers;
 mod logger;
 mod meta_text;
+mod tcp_server;
 mod util;
 
 use crate::handlers::{
@@ -221,40 +222,48 @@ impl ServerMessageProcessor {
     }
 }
 
-#[allow(unused)]
 pub async fn run_ls(cmd_args: CmdArgs) -> Result<(), Box<dyn Error + Sync + Send>> {
-    let (connection, threads) = match cmd_args.communication {
-        cmd_args::Communication::Stdio => Connection::stdio(),
-        cmd_args::Communication::Tcp => {
-            let port = cmd_args.port;
-            let ip = cmd_args.ip.clone();
-            let addr = (ip.as_str(), port);
-            Connection::listen(addr).unwrap()
+    match cmd_args.communication {
+        cmd_args::Communication::Stdio => {
+            let (connection, threads) = Connection::stdio();
+            serve_connection(connection, cmd_args).await?;
+            threads.join()?;
         }
-    };
-
-    let (id, params) = connection.initialize_start()?;
-    let initialization_params: InitializeParams = serde_json::from_value(params).unwrap();
-    let server_capabilities = server_capabilities(&initialization_params.capabilities);
-    let initialize_data = serde_json::json!({
-        "capabilities": server_capabilities,
-        "serverInfo": {
-            "name": CRATE_NAME,
-            "version": CRATE_VERSION
-        }
-    });
-
-    connection.initialize_finish(id, initialize_data)?;
-
-    // Create async connection wrapper
-    let mut async_connection = AsyncConnection::from_sync(connection);
-    main_loop(async_connection, initialization_params, cmd_args).await?;
-    threads.join()?;
+        cmd_args::Communication::Tcp => tcp_server::listen(cmd_args).await?,
+    }
 
     eprintln!("Server shutting down.");
     Ok(())
 }
 
+/// Run the initialize handshake and the main loop of a single client connection.
+async fn serve_connection(
+    connection: Connection,
+    cmd_args: CmdArgs,
+) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (connection, initialization_params) =
+        tokio::task::spawn_blocking(move || -> Result<_, Box<dyn Error + Sync + Send>> {
+            let (id, params) = connection.initialize_start()?;
+            let initialization_params: InitializeParams = serde_json::from_value(params)?;
+            let server_capabilities = server_capabilities(&initialization_params.capabilities);
+            let initialize_data = serde_json::json!({
+                "capabilities": server_capabilities,
+                "serverInfo": {
+                    "name": CRATE_NAME,
+                    "version": CRATE_VERSION
+                }
+            });
+
+            connection.initialize_finish(id, initialize_data)?;
+            Ok((connection, initialization_params))
+        })
+        .await??;
+
+    // Create async connection wrapper
+    let async_connection = AsyncConnection::from_sync(connection);
+    main_loop(async_connection, initialization_params, cmd_args).await
+}
+
 /// LSP Server manages the entire server lifecycle
 struct LspServer {
     connection: AsyncConnection,
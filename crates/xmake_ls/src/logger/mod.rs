@@ -1,6 +1,10 @@
 mod best_log_path;
 
-use std::{env, fs, path::PathBuf};
+use std::{
+    env, fs,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use best_log_path::get_best_log_dir;
 use chrono::Local;
@@ -13,7 +17,14 @@ use crate::cmd_args::{CmdArgs, LogLevel};
 const CRATE_NAME: &str = env!("CARGO_PKG_NAME");
 const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// In tcp mode every client runs the initialization, only the first one sets up the logger
+static LOGGER_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
 pub fn init_logger(root: Option<&str>, cmd_args: &CmdArgs) {
+    if LOGGER_INITIALIZED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
     let level = match cmd_args.log_level {
         LogLevel::Error => LevelFilter::Error,
         LogLevel::Warn => LevelFilter::Warn,
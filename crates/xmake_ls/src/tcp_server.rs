@@ -0,0 +1,115 @@
+use std::{
+    error::Error,
+    io::{self, BufReader},
+    net::{Shutdown, TcpStream},
+    thread,
+};
+
+use crossbeam_channel::unbounded;
+use lsp_server::{Connection, Message};
+use tokio::net::TcpListener;
+
+use crate::{cmd_args::CmdArgs, serve_connection};
+
+/// Accept clients on `--ip`/`--port` until the process is interrupted.
+///
+/// Every client gets its own `ServerContext`, a client that disconnects (or sends `exit`)
+/// only ends its own session.
+pub async fn listen(cmd_args: CmdArgs) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let listener = TcpListener::bind((cmd_args.ip.as_str(), cmd_args.port)).await?;
+    eprintln!("Listening on {}", listener.local_addr()?);
+
+    loop {
+        let (stream, addr) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    eprintln!("Failed to accept connection: {}", e);
+                    continue;
+                }
+            },
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        };
+
+        eprintln!("Client connected: {}", addr);
+        let cmd_args = cmd_args.clone();
+        tokio::spawn(async move {
+            if let Err(e) = serve_tcp_client(stream, cmd_args).await {
+                eprintln!("Client {} error: {}", addr, e);
+            }
+            eprintln!("Client disconnected: {}", addr);
+        });
+    }
+}
+
+async fn serve_tcp_client(
+    stream: tokio::net::TcpStream,
+    cmd_args: CmdArgs,
+) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let stream = stream.into_std()?;
+    stream.set_nonblocking(false)?;
+    let (connection, threads) = socket_transport(stream)?;
+    let result = serve_connection(connection, cmd_args).await;
+    threads.close();
+    result
+}
+
+/// Reader and writer threads of one TCP client.
+struct SocketThreads {
+    stream: TcpStream,
+    reader: thread::JoinHandle<()>,
+}
+
+impl SocketThreads {
+    /// Close the socket, so the client sees the end of the session even if some
+    /// background task still holds a sender of the connection.
+    fn close(self) {
+        let _ = self.stream.shutdown(Shutdown::Both);
+        let _ = self.reader.join();
+    }
+}
+
+/// Same as the transport behind `Connection::listen`, but a broken or closed socket ends the
+/// threads instead of panicking.
+fn socket_transport(stream: TcpStream) -> io::Result<(Connection, SocketThreads)> {
+    let read_stream = stream.try_clone()?;
+    let mut write_stream = stream.try_clone()?;
+
+    let (reader_sender, reader_receiver) = unbounded::<Message>();
+    let reader = thread::spawn(move || {
+        let mut buf_read = BufReader::new(read_stream);
+        loop {
+            match Message::read(&mut buf_read) {
+                Ok(Some(msg)) => {
+                    let is_exit = matches!(&msg, Message::Notification(n) if n.method == "exit");
+                    if reader_sender.send(msg).is_err() || is_exit {
+                        break;
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    log::warn!("Failed to read message from client: {}", e);
+                    break;
+                }
+            }
+        }
+    });
+
+    let (writer_sender, writer_receiver) = unbounded::<Message>();
+    thread::spawn(move || {
+        for msg in writer_receiver {
+            if let Err(e) = msg.write(&mut write_stream) {
+                log::warn!("Failed to write message to client: {}", e);
+                break;
+            }
+        }
+    });
+
+    Ok((
+        Connection {
+            sender: writer_sender,
+            receiver: reader_receiver,
+        },
+        SocketThreads { stream, reader },
+    ))
+}
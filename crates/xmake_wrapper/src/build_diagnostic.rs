@@ -0,0 +1,154 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Severity of a diagnostic reported by a compiler or by xmake
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildDiagnosticSeverity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl BuildDiagnosticSeverity {
+    fn parse(text: &str) -> Self {
+        match text {
+            "error" | "fatal error" => BuildDiagnosticSeverity::Error,
+            "warning" => BuildDiagnosticSeverity::Warning,
+            _ => BuildDiagnosticSeverity::Note,
+        }
+    }
+}
+
+/// A diagnostic found in the output of a build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildDiagnostic {
+    /// File path as printed, may be relative to the project directory
+    pub file: String,
+    /// 1-based line
+    pub line: u32,
+    /// 1-based column, if the tool reports one
+    pub column: Option<u32>,
+    pub severity: BuildDiagnosticSeverity,
+    /// Diagnostic code, e.g. `C2065` for MSVC
+    pub code: Option<String>,
+    pub message: String,
+}
+
+static ANSI_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\x1b\[[0-?]*[ -/]*[@-~]").unwrap());
+
+// gcc/clang: `src/main.cpp:3:5: error: message`, xmake may prefix the first line with `error: `
+static GCC_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:error: )?(?P<file>(?:[A-Za-z]:)?[^:]+):(?P<line>\d+):(?:(?P<col>\d+):)?\s*(?P<sev>fatal error|error|warning|note):\s*(?P<msg>.*)$",
+    )
+    .unwrap()
+});
+
+// MSVC: `src\main.cpp(3,5): error C2065: message` or `src\main.cpp(3): warning C4996: message`
+static MSVC_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:error: )?(?P<file>[^()]+)\((?P<line>\d+)(?:,(?P<col>\d+))?\)\s*:\s*(?P<sev>fatal error|error|warning|note)\s*(?P<code>[A-Z]+\d+)?\s*:\s*(?P<msg>.*)$",
+    )
+    .unwrap()
+});
+
+// xmake: `error: xmake.lua:12: message` or `warning: ./xmake.lua:5: message`
+static XMAKE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?P<sev>error|warning): (?P<file>(?:[A-Za-z]:)?[^:]+\.lua):(?P<line>\d+): (?P<msg>.*)$",
+    )
+    .unwrap()
+});
+
+/// Parse gcc, clang, MSVC and xmake diagnostics from the output of a build
+pub fn parse_build_diagnostics(output: &str) -> Vec<BuildDiagnostic> {
+    let output = ANSI_RE.replace_all(output, "");
+    output
+        .lines()
+        .filter_map(|line| parse_build_diagnostic(line.trim()))
+        .collect()
+}
+
+fn parse_build_diagnostic(line: &str) -> Option<BuildDiagnostic> {
+    // the xmake format has to be checked first, the gcc pattern accepts `error: ` as prefix
+    let captures = XMAKE_RE
+        .captures(line)
+        .or_else(|| GCC_RE.captures(line))
+        .or_else(|| MSVC_RE.captures(line))?;
+
+    Some(BuildDiagnostic {
+        file: captures["file"].trim().to_string(),
+        line: captures["line"].parse().ok()?,
+        column: captures
+            .name("col")
+            .and_then(|col| col.as_str().parse().ok()),
+        severity: BuildDiagnosticSeverity::parse(&captures["sev"]),
+        code: captures.name("code").map(|code| code.as_str().to_string()),
+        message: captures["msg"].trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gcc() {
+        let output = "[ 50%]: compiling.release src/main.cpp\n\
+            error: src/main.cpp:3:5: error: 'x' was not declared in this scope\n\
+            src/main.cpp:7:10: warning: unused variable 'y' [-Wunused-variable]\n\
+            In file included from src/main.cpp:1:\n\
+            C:\\proj\\src\\foo.h:2:1: note: declared here\n";
+        let diagnostics = parse_build_diagnostics(output);
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(
+            diagnostics[0],
+            BuildDiagnostic {
+                file: "src/main.cpp".to_string(),
+                line: 3,
+                column: Some(5),
+                severity: BuildDiagnosticSeverity::Error,
+                code: None,
+                message: "'x' was not declared in this scope".to_string(),
+            }
+        );
+        assert_eq!(diagnostics[1].severity, BuildDiagnosticSeverity::Warning);
+        assert_eq!(diagnostics[2].file, "C:\\proj\\src\\foo.h");
+        assert_eq!(diagnostics[2].severity, BuildDiagnosticSeverity::Note);
+    }
+
+    #[test]
+    fn test_parse_msvc() {
+        let output = "src\\main.cpp(3,5): error C2065: 'x': undeclared identifier\r\n\
+            C:\\proj\\src\\main.cpp(7): warning C4996: 'strcpy': This function may be unsafe.\r\n";
+        let diagnostics = parse_build_diagnostics(output);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].file, "src\\main.cpp");
+        assert_eq!(diagnostics[0].column, Some(5));
+        assert_eq!(diagnostics[0].code.as_deref(), Some("C2065"));
+        assert_eq!(diagnostics[0].message, "'x': undeclared identifier");
+        assert_eq!(diagnostics[1].file, "C:\\proj\\src\\main.cpp");
+        assert_eq!(diagnostics[1].line, 7);
+        assert_eq!(diagnostics[1].column, None);
+        assert_eq!(diagnostics[1].severity, BuildDiagnosticSeverity::Warning);
+    }
+
+    #[test]
+    fn test_parse_xmake() {
+        let output =
+            "\x1b[31merror: \x1b[0mxmake.lua:12: attempt to call a nil value (global 'add_fils')\n";
+        let diagnostics = parse_build_diagnostics(output);
+        assert_eq!(
+            diagnostics,
+            vec![BuildDiagnostic {
+                file: "xmake.lua".to_string(),
+                line: 12,
+                column: None,
+                severity: BuildDiagnosticSeverity::Error,
+                code: None,
+                message: "attempt to call a nil value (global 'add_fils')".to_string(),
+            }]
+        );
+    }
+}
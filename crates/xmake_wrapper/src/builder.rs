@@ -1,4 +1,9 @@
-use crate::{XmakeCommand, XmakeCommandType, XmakeError, XmakeOutput, XmakeWrapper};
+use std::path::Path;
+
+use crate::{
+    ExecuteOptions, OutputLine, XmakeCommand, XmakeCommandType, XmakeError, XmakeOutput,
+    XmakeWrapper,
+};
 
 /// xmake command builder
 #[derive(Debug, Clone)]
@@ -59,6 +64,20 @@ impl XmakeCommandBuilder {
         self.wrapper.execute(&self.command).await
     }
 
+    /// Execute the command, reporting output lines as they are printed
+    pub async fn execute_streaming<F>(
+        self,
+        options: ExecuteOptions,
+        on_line: F,
+    ) -> Result<XmakeOutput, XmakeError>
+    where
+        F: FnMut(OutputLine),
+    {
+        self.wrapper
+            .execute_streaming(&self.command, options, on_line)
+            .await
+    }
+
     /// Build project
     pub fn build() -> impl Fn(XmakeWrapper) -> XmakeCommandBuilder {
         |wrapper| XmakeCommandBuilder::new(wrapper).command_type(XmakeCommandType::Build)
@@ -114,6 +133,11 @@ impl XmakeCommandBuilder {
         |wrapper| XmakeCommandBuilder::new(wrapper).command_type(XmakeCommandType::Test)
     }
 
+    /// Generate project files
+    pub fn project() -> impl Fn(XmakeWrapper) -> XmakeCommandBuilder {
+        |wrapper| XmakeCommandBuilder::new(wrapper).command_type(XmakeCommandType::Project)
+    }
+
     /// Custom command
     pub fn custom<S: Into<String>>(cmd: S) -> impl Fn(XmakeWrapper) -> XmakeCommandBuilder {
         let cmd = cmd.into();
@@ -180,8 +204,43 @@ impl XmakeWrapper {
         XmakeCommandBuilder::test()(self.clone())
     }
 
+    /// Generate project files
+    pub fn project(&self) -> XmakeCommandBuilder {
+        XmakeCommandBuilder::project()(self.clone())
+    }
+
+    /// Generate `compile_commands.json` into `output_dir`, the project dir when `None`
+    pub fn compile_commands(&self, output_dir: Option<&Path>) -> XmakeCommandBuilder {
+        let builder = self.project().arg("-k").arg("compile_commands");
+        match output_dir {
+            Some(output_dir) => builder.arg(output_dir.to_string_lossy()),
+            None => builder,
+        }
+    }
+
     /// Custom command
     pub fn custom<S: Into<String>>(&self, cmd: S) -> XmakeCommandBuilder {
         XmakeCommandBuilder::custom(cmd)(self.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_commands_args() {
+        let xmake = XmakeWrapper::new();
+        assert_eq!(
+            xmake.compile_commands(None).command.build_args(),
+            vec!["project", "-k", "compile_commands"]
+        );
+        assert_eq!(
+            xmake
+                .compile_commands(Some(Path::new(".vscode")))
+                .command
+                .build_args(),
+            vec!["project", "-k", "compile_commands", ".vscode"]
+        );
+    }
+}
@@ -25,6 +25,8 @@ pub enum XmakeCommandType {
     Package,
     /// Test project
     Test,
+    /// Generate project files
+    Project,
     /// Custom command
     Custom(String),
 }
@@ -44,6 +46,7 @@ impl XmakeCommandType {
             XmakeCommandType::Show => "show",
             XmakeCommandType::Package => "require",
             XmakeCommandType::Test => "test",
+            XmakeCommandType::Project => "project",
             XmakeCommandType::Custom(cmd) => cmd,
         }
     }
@@ -25,6 +25,10 @@ pub enum XmakeError {
         command: String,
         timeout_secs: u64,
     },
+    /// Command was cancelled and its process tree killed
+    Cancelled {
+        command: String,
+    },
     VersionParseError {
         version_str: String,
     },
@@ -71,6 +75,9 @@ impl fmt::Display for XmakeError {
                     command, timeout_secs
                 )
             }
+            XmakeError::Cancelled { command } => {
+                write!(f, "Command '{}' was cancelled", command)
+            }
             XmakeError::VersionParseError { version_str } => {
                 write!(
                     f,
@@ -1,12 +1,19 @@
 use crate::{XmakeCommand, XmakeError, XmakeOutput, XmakeWrapper};
 use log::{debug, error, info, warn};
 use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::Command;
+use tokio::sync::mpsc;
 use tokio::time::{Duration, timeout};
+use tokio_util::sync::CancellationToken;
 
 /// Default command timeout in seconds
 const DEFAULT_TIMEOUT_SECS: u64 = 300; // 5 minutes
 
+/// How long the output of a streaming command is still read after it exits, processes it
+/// started in the background may keep the pipes open forever
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_millis(500);
+
 /// Execute an xmake command
 pub async fn execute_command(
     wrapper: &XmakeWrapper,
@@ -26,44 +33,7 @@ pub async fn execute_command_with_timeout(
 
     debug!("Executing xmake command: {}", command_str);
 
-    // Build tokio command
-    let mut command = Command::new(&wrapper.xmake_env);
-    command
-        .args(&args)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .stdin(Stdio::null());
-
-    // Set working directory
-    if let Some(working_dir) = &wrapper.working_dir {
-        if !working_dir.exists() {
-            let error_msg = format!(
-                "Working directory does not exist: {}",
-                working_dir.display()
-            );
-            error!("{}", error_msg);
-            return Err(XmakeError::InvalidWorkingDirectory {
-                path: working_dir.display().to_string(),
-            });
-        }
-        debug!("Setting working directory to: {}", working_dir.display());
-        command.current_dir(working_dir);
-    }
-
-    // Set environment variables
-    if cmd.inherit_env {
-        for (key, value) in &cmd.env_vars {
-            debug!("Setting environment variable: {}={}", key, value);
-            command.env(key, value);
-        }
-    } else {
-        debug!("Clearing environment variables");
-        command.env_clear();
-        for (key, value) in &cmd.env_vars {
-            debug!("Setting environment variable: {}={}", key, value);
-            command.env(key, value);
-        }
-    }
+    let mut command = prepare_command(wrapper, cmd, &args)?;
 
     // Execute command
     let command_str_clone = command_str.clone();
@@ -116,3 +86,438 @@ pub async fn execute_command_with_timeout(
         }
     }
 }
+
+/// A line printed by a running command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputLine {
+    Stdout(String),
+    Stderr(String),
+}
+
+/// Options of a streaming execution
+#[derive(Debug, Clone, Default)]
+pub struct ExecuteOptions {
+    /// Kill the command after this duration, `None` means no timeout
+    pub timeout: Option<Duration>,
+    /// Kill the command when this token is cancelled
+    pub cancel_token: Option<CancellationToken>,
+}
+
+impl ExecuteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the cancellation token
+    pub fn cancel_token(mut self, cancel_token: CancellationToken) -> Self {
+        self.cancel_token = Some(cancel_token);
+        self
+    }
+}
+
+/// Execute a command and report every output line to `on_line` as soon as it is printed.
+///
+/// The returned output still contains the whole stdout/stderr. On timeout or cancellation
+/// the whole process tree of the command is killed.
+pub async fn execute_command_streaming<F>(
+    wrapper: &XmakeWrapper,
+    cmd: &XmakeCommand,
+    options: ExecuteOptions,
+    mut on_line: F,
+) -> Result<XmakeOutput, XmakeError>
+where
+    F: FnMut(OutputLine),
+{
+    let args = cmd.build_args();
+    let command_str = format!("{} {}", wrapper.xmake_env, args.join(" "));
+
+    debug!("Executing xmake command (streaming): {}", command_str);
+
+    let mut command = prepare_command(wrapper, cmd, &args)?;
+    command.kill_on_drop(true);
+    // run the command in its own process group, so the whole tree can be killed
+    #[cfg(unix)]
+    command.process_group(0);
+
+    info!("Starting xmake command execution: {}", command_str);
+    let mut child = command.spawn()?;
+    let pid = child.id();
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let mut readers = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        readers.push(tokio::spawn(read_lines(
+            stdout,
+            sender.clone(),
+            OutputLine::Stdout,
+        )));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        readers.push(tokio::spawn(read_lines(stderr, sender, OutputLine::Stderr)));
+    }
+
+    let execution_future = async {
+        let mut stdout = String::new();
+        let mut stderr = String::new();
+        let mut on_output = |line: OutputLine| {
+            match &line {
+                OutputLine::Stdout(text) => push_line(&mut stdout, text),
+                OutputLine::Stderr(text) => push_line(&mut stderr, text),
+            }
+            on_line(line);
+        };
+
+        // wait for the exit while reading, the channel is only closed once both pipes are
+        // closed, which never happens while a daemon started by the command keeps them open
+        let mut status = None;
+        loop {
+            tokio::select! {
+                biased;
+                line = receiver.recv() => match line {
+                    Some(line) => on_output(line),
+                    None => break,
+                },
+                result = child.wait() => {
+                    status = Some(result?);
+                    break;
+                }
+            }
+        }
+        let status = match status {
+            Some(status) => status,
+            None => child.wait().await?,
+        };
+
+        // the output printed right before the exit may still be in the pipes
+        let drain = async {
+            while let Some(line) = receiver.recv().await {
+                on_output(line);
+            }
+        };
+        if timeout(OUTPUT_DRAIN_TIMEOUT, drain).await.is_err() {
+            debug!("Output pipes still open after exit: {}", command_str);
+        }
+        for reader in &readers {
+            reader.abort();
+        }
+
+        Ok::<_, XmakeError>(XmakeOutput::new(
+            status.code().unwrap_or(-1),
+            stdout,
+            stderr,
+        ))
+    };
+
+    let cancelled = async {
+        match &options.cancel_token {
+            Some(cancel_token) => cancel_token.cancelled().await,
+            None => std::future::pending().await,
+        }
+    };
+    let timed_out = async {
+        match options.timeout {
+            Some(duration) => {
+                tokio::time::sleep(duration).await;
+                duration
+            }
+            None => std::future::pending().await,
+        }
+    };
+
+    let result = tokio::select! {
+        result = execution_future => result?,
+        _ = cancelled => {
+            warn!("xmake command cancelled: {}", command_str);
+            kill_process_tree(pid);
+            return Err(XmakeError::Cancelled {
+                command: command_str,
+            });
+        }
+        duration = timed_out => {
+            error!(
+                "xmake command timed out after {:?}: {}",
+                duration, command_str
+            );
+            kill_process_tree(pid);
+            return Err(XmakeError::Timeout {
+                command: command_str,
+                timeout_secs: duration.as_secs(),
+            });
+        }
+    };
+
+    if result.is_success() {
+        info!("xmake command completed successfully: {}", command_str);
+        Ok(result)
+    } else {
+        warn!(
+            "xmake command failed with status {}: {}",
+            result.status_code, command_str
+        );
+        Err(XmakeError::ExecutionFailed {
+            command: command_str,
+            status_code: result.status_code,
+            stdout: result.stdout,
+            stderr: result.stderr,
+        })
+    }
+}
+
+async fn read_lines<R, F>(reader: R, sender: mpsc::UnboundedSender<OutputLine>, make_line: F)
+where
+    R: AsyncRead + Unpin,
+    F: Fn(String) -> OutputLine,
+{
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf).await {
+            Ok(0) => break,
+            Ok(_) => {
+                let text = String::from_utf8_lossy(&buf);
+                let text = text.trim_end_matches(['\r', '\n']).to_string();
+                if sender.send(make_line(text)).is_err() {
+                    break;
+                }
+            }
+            Err(e) => {
+                debug!("Failed to read command output: {}", e);
+                break;
+            }
+        }
+    }
+}
+
+fn push_line(output: &mut String, line: &str) {
+    output.push_str(line);
+    output.push('\n');
+}
+
+/// Kill the process with `pid` and all of its descendants
+fn kill_process_tree(pid: Option<u32>) {
+    let Some(pid) = pid else {
+        return;
+    };
+
+    #[cfg(unix)]
+    {
+        // the child leads its own process group, see `execute_command_streaming`
+        let result = unsafe { libc::kill(-(pid as libc::pid_t), libc::SIGKILL) };
+        if result != 0 {
+            debug!(
+                "Failed to kill process group {}: {}",
+                pid,
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        let result = std::process::Command::new("taskkill")
+            .args(["/T", "/F", "/PID", &pid.to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+        if let Err(e) = result {
+            debug!("Failed to kill process tree {}: {}", pid, e);
+        }
+    }
+}
+
+/// Build the tokio command for `cmd`, with piped stdout/stderr
+fn prepare_command(
+    wrapper: &XmakeWrapper,
+    cmd: &XmakeCommand,
+    args: &[String],
+) -> Result<Command, XmakeError> {
+    let mut command = Command::new(&wrapper.xmake_env);
+    command
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .stdin(Stdio::null());
+
+    // Set working directory
+    if let Some(working_dir) = &wrapper.working_dir {
+        if !working_dir.exists() {
+            let error_msg = format!(
+                "Working directory does not exist: {}",
+                working_dir.display()
+            );
+            error!("{}", error_msg);
+            return Err(XmakeError::InvalidWorkingDirectory {
+                path: working_dir.display().to_string(),
+            });
+        }
+        debug!("Setting working directory to: {}", working_dir.display());
+        command.current_dir(working_dir);
+    }
+
+    // Set environment variables
+    if cmd.inherit_env {
+        for (key, value) in &cmd.env_vars {
+            debug!("Setting environment variable: {}={}", key, value);
+            command.env(key, value);
+        }
+    } else {
+        debug!("Clearing environment variables");
+        command.env_clear();
+        for (key, value) in &cmd.env_vars {
+            debug!("Setting environment variable: {}={}", key, value);
+            command.env(key, value);
+        }
+    }
+
+    Ok(command)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::XmakeCommandType;
+
+    fn shell(script: &str) -> (XmakeWrapper, XmakeCommand) {
+        let wrapper = XmakeWrapper::with_path("sh");
+        let cmd = XmakeCommand::new(XmakeCommandType::Custom("-c".to_string())).arg(script);
+        (wrapper, cmd)
+    }
+
+    fn is_running(pid: &str) -> bool {
+        // a killed orphan may stay a zombie when nothing reaps it
+        match std::fs::read_to_string(format!("/proc/{}/stat", pid)) {
+            Ok(stat) => !stat.contains(") Z "),
+            Err(_) => false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_streaming_lines() {
+        let (wrapper, cmd) = shell("echo one; echo two >&2; echo three");
+        let mut lines = Vec::new();
+        let output = execute_command_streaming(&wrapper, &cmd, ExecuteOptions::new(), |line| {
+            lines.push(line)
+        })
+        .await
+        .unwrap();
+
+        let stdout_lines = lines
+            .iter()
+            .filter(|line| matches!(line, OutputLine::Stdout(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stdout_lines,
+            vec![
+                OutputLine::Stdout("one".to_string()),
+                OutputLine::Stdout("three".to_string())
+            ]
+        );
+        assert!(lines.contains(&OutputLine::Stderr("two".to_string())));
+        assert_eq!(output.stdout, "one\nthree\n");
+        assert_eq!(output.stderr, "two\n");
+    }
+
+    #[tokio::test]
+    async fn test_streaming_before_exit() {
+        let (wrapper, cmd) = shell("echo first; sleep 1; echo second");
+        let start = Instant::now();
+        let mut elapsed = Vec::new();
+        execute_command_streaming(&wrapper, &cmd, ExecuteOptions::new(), |_| {
+            elapsed.push(start.elapsed())
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(elapsed.len(), 2);
+        assert!(elapsed[0] < Duration::from_millis(800));
+        assert!(elapsed[1] >= Duration::from_millis(800));
+    }
+
+    #[tokio::test]
+    async fn test_failed_status() {
+        let (wrapper, cmd) = shell("echo oops; exit 3");
+        let result = execute_command_streaming(&wrapper, &cmd, ExecuteOptions::new(), |_| {}).await;
+        match result {
+            Err(XmakeError::ExecutionFailed {
+                status_code,
+                stdout,
+                ..
+            }) => {
+                assert_eq!(status_code, 3);
+                assert_eq!(stdout, "oops\n");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_background_process_keeps_pipes_open() {
+        let (wrapper, cmd) = shell("sleep 5 & echo started");
+        let start = Instant::now();
+        let output = execute_command_streaming(&wrapper, &cmd, ExecuteOptions::new(), |_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(output.stdout, "started\n");
+        assert!(start.elapsed() < Duration::from_secs(3));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_kills_process_tree() {
+        let pid_file = std::env::temp_dir().join(format!("xmake_cancel_{}", std::process::id()));
+        let _ = std::fs::remove_file(&pid_file);
+        let script = format!("sleep 30 & echo $! > {}; wait", pid_file.display());
+        let (wrapper, cmd) = shell(&script);
+        let cancel_token = CancellationToken::new();
+        let options = ExecuteOptions::new().cancel_token(cancel_token.clone());
+
+        let canceller = {
+            let pid_file = pid_file.clone();
+            async move {
+                while !pid_file.exists() {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                cancel_token.cancel();
+            }
+        };
+        let (result, _) = tokio::join!(
+            execute_command_streaming(&wrapper, &cmd, options, |_| {}),
+            canceller
+        );
+
+        assert!(matches!(result, Err(XmakeError::Cancelled { .. })));
+        let pid = std::fs::read_to_string(&pid_file).unwrap();
+        let pid = pid.trim();
+        std::fs::remove_file(&pid_file).unwrap();
+        // the kill signal is delivered asynchronously
+        for _ in 0..50 {
+            if !is_running(pid) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("grandchild {} is still running", pid);
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let (wrapper, cmd) = shell("sleep 30");
+        let options = ExecuteOptions::new().timeout(Duration::from_millis(200));
+        let start = Instant::now();
+        let result = execute_command_streaming(&wrapper, &cmd, options, |_| {}).await;
+
+        assert!(matches!(result, Err(XmakeError::Timeout { .. })));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,14 +1,18 @@
+mod build_diagnostic;
 mod builder;
 mod command;
 mod error;
 mod executor;
+mod template;
 mod version;
 
+pub use build_diagnostic::*;
 pub use builder::*;
 pub use command::*;
 pub use error::*;
 pub use executor::*;
 use log::debug;
+pub use template::*;
 pub use version::XmakeVersion;
 
 use std::{path::PathBuf, process::Stdio};
@@ -60,6 +64,19 @@ impl XmakeWrapper {
         execute_command(self, cmd).await
     }
 
+    /// Execute a xmake command, reporting output lines as they are printed
+    pub async fn execute_streaming<F>(
+        &self,
+        cmd: &XmakeCommand,
+        options: ExecuteOptions,
+        on_line: F,
+    ) -> Result<XmakeOutput, XmakeError>
+    where
+        F: FnMut(OutputLine),
+    {
+        execute_command_streaming(self, cmd, options, on_line).await
+    }
+
     /// Check whether xmake is available
     pub async fn check_available(&self) -> bool {
         let result = Command::new(&self.xmake_env)
@@ -118,6 +135,21 @@ impl XmakeWrapper {
         None
     }
 
+    /// The `xmake create` templates of the installed xmake
+    pub async fn get_templates(&self) -> Vec<XmakeTemplate> {
+        let program_dir = match self.get_xmake_program_dir().await {
+            Some(program_dir) => Some(program_dir),
+            None => self
+                .get_xmake_path()
+                .await
+                .and_then(|path| path.parent().map(|parent| parent.to_path_buf())),
+        };
+
+        program_dir
+            .map(|program_dir| list_templates(&program_dir.join("templates")))
+            .unwrap_or_default()
+    }
+
     pub async fn get_xmake_version(&self) -> Result<XmakeVersion, XmakeError> {
         debug!("Getting xmake version from: {}", self.xmake_env);
         let output = Command::new(&self.xmake_env)
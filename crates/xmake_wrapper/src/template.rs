@@ -0,0 +1,89 @@
+use std::path::{Path, PathBuf};
+
+/// A project template of `xmake create`, `templates/<language>/<name>` in the xmake program dir
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct XmakeTemplate {
+    pub language: String,
+    pub name: String,
+}
+
+/// Templates found in `templates_dir`, sorted by language and name
+pub fn list_templates(templates_dir: &Path) -> Vec<XmakeTemplate> {
+    let mut templates = Vec::new();
+    for language_dir in read_sub_dirs(templates_dir) {
+        let Some(language) = get_dir_name(&language_dir) else {
+            continue;
+        };
+        for template_dir in read_sub_dirs(&language_dir) {
+            if let Some(name) = get_dir_name(&template_dir) {
+                templates.push(XmakeTemplate {
+                    language: language.clone(),
+                    name,
+                });
+            }
+        }
+    }
+
+    templates.sort();
+    templates
+}
+
+/// Languages of `templates`, in order and without duplicates
+pub fn get_template_languages(templates: &[XmakeTemplate]) -> Vec<&str> {
+    let mut languages: Vec<&str> = Vec::new();
+    for template in templates {
+        if !languages.contains(&template.language.as_str()) {
+            languages.push(&template.language);
+        }
+    }
+    languages
+}
+
+fn read_sub_dirs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect()
+}
+
+fn get_dir_name(dir: &Path) -> Option<String> {
+    dir.file_name()?.to_str().map(|name| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_templates() {
+        let templates_dir =
+            std::env::temp_dir().join(format!("xmake_wrapper_templates_{}", std::process::id()));
+        for dir in ["c++/console", "c++/qt.widgetapp", "c/static", "c/shared"] {
+            std::fs::create_dir_all(templates_dir.join(dir)).unwrap();
+        }
+        // files next to the templates are not templates
+        std::fs::write(templates_dir.join("c/README.md"), "").unwrap();
+
+        let templates = list_templates(&templates_dir);
+        std::fs::remove_dir_all(&templates_dir).unwrap();
+
+        let names = templates
+            .iter()
+            .map(|template| format!("{}/{}", template.language, template.name))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["c/shared", "c/static", "c++/console", "c++/qt.widgetapp"]
+        );
+        assert_eq!(get_template_languages(&templates), vec!["c", "c++"]);
+    }
+
+    #[test]
+    fn test_missing_templates_dir() {
+        assert!(list_templates(Path::new("/path/that/does/not/exist")).is_empty());
+    }
+}